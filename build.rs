@@ -0,0 +1,460 @@
+//! Generates the `Status` and `Space` enumerations from the vendored `sl_status.h`.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::Path;
+use std::{env, fs};
+
+/// Path of the vendored SDK header.
+const HEADER: &str = "vendor/sl_status.h";
+
+/// Path of the table of SDK releases defining the statuses.
+const SINCE_TABLE: &str = "vendor/sl_status_since.txt";
+
+/// Path of the table of categories of the statuses.
+const CATEGORY_TABLE: &str = "vendor/sl_status_category.txt";
+
+/// Common prefix of all status and space defines.
+const PREFIX: &str = "SL_STATUS_";
+
+/// Suffix identifying space defines.
+const SPACE_SUFFIX: &str = "_SPACE";
+
+/// Name of the space mask define.
+const SPACE_MASK: &str = "SL_STATUS_SPACE_MASK";
+
+/// Variant names which deviate from the camel-cased SDK names.
+const VARIANT_NAMES: &[(&str, &str)] = &[
+    ("SL_STATUS_CAN_CANOPEN_SPACE", "CanCanOpen"),
+    ("SL_STATUS_WISUN_SPACE", "WiSun"),
+    ("SL_STATUS_BLUETOOTH_CTRL_SPACE", "BluetoothController"),
+    ("SL_STATUS_BLUETOOTH_ATT_SPACE", "BluetoothAttribute"),
+    ("SL_STATUS_BLUETOOTH_SMP_SPACE", "BluetoothSecurityManager"),
+    ("SL_STATUS_EMPTY", "StatusEmpty"),
+    ("SL_STATUS_FULL", "StatusFull"),
+];
+
+/// Documentation and attributes of the generated `Space` enum.
+const SPACE_ATTRIBUTES: &str = r#"    /// Space codes common across all platforms.
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Ord, PartialOrd, FromPrimitive)]
+"#;
+
+/// Documentation and attributes of the generated `Status` enum.
+const STATUS_ATTRIBUTES: &str = r#"    /// Status codes common across all platforms.
+    ///
+    /// # Documentation
+    ///
+    /// See [docs.silabs.com](https://docs.silabs.com/mcu/5.9/efr32bg1/group-sl-status) for further information.
+    ///
+    /// Status codes which are not known to this version of the crate cannot be represented by this
+    /// enum. Use [`RawStatus`] to handle them losslessly.
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Ord, PartialOrd, FromPrimitive)]
+"#;
+
+/// A `#define` of the SDK header.
+struct Define {
+    name: String,
+    variant: String,
+    value: u32,
+    description: String,
+}
+
+impl Define {
+    /// Parses a line of the form `#define NAME ((sl_status_t)0xVALUE)  ///< Description`.
+    ///
+    /// Other lines, including defines without a hexadecimal value, are skipped.
+    fn parse(line: &str) -> Option<Self> {
+        let line = line.trim().strip_prefix("#define")?.trim_start();
+        let end = line
+            .find(|char: char| !char.is_ascii_alphanumeric() && char != '_')
+            .unwrap_or(line.len());
+        let (name, rest) = line.split_at(end);
+        name.strip_prefix(PREFIX)?;
+        let (value, description) = rest.split_once("///<").unwrap_or((rest, ""));
+        let value = value
+            .trim()
+            .trim_start_matches("((sl_status_t)")
+            .trim_end_matches(')')
+            .trim()
+            .strip_prefix("0x")?;
+        let value = u32::from_str_radix(value, 16).ok()?;
+
+        Some(Self {
+            name: name.to_owned(),
+            variant: variant_name(name),
+            value,
+            description: description.trim().to_owned(),
+        })
+    }
+
+    /// Determines whether the define denotes a space.
+    fn is_space(&self) -> bool {
+        self.name == SPACE_MASK || self.name.ends_with(SPACE_SUFFIX)
+    }
+}
+
+fn main() {
+    println!("cargo::rerun-if-changed={HEADER}");
+    println!("cargo::rerun-if-changed={SINCE_TABLE}");
+    println!("cargo::rerun-if-changed={CATEGORY_TABLE}");
+
+    let header = fs::read_to_string(HEADER).expect("the vendored header should be readable");
+    let (spaces, statuses): (Vec<_>, Vec<_>) = header
+        .lines()
+        .filter_map(Define::parse)
+        .partition(Define::is_space);
+    let (masks, spaces): (Vec<_>, Vec<_>) = spaces
+        .into_iter()
+        .partition(|space| space.name == SPACE_MASK);
+    let mask = masks
+        .first()
+        .unwrap_or_else(|| panic!("{HEADER} does not define {SPACE_MASK}"))
+        .value;
+    let spaces = by_value(spaces);
+    let statuses = by_value(statuses);
+    let since = read_table(SINCE_TABLE, &statuses, parse_version);
+    let categories = read_table(CATEGORY_TABLE, &statuses, |_, category| category.to_owned());
+
+    let out_dir = env::var_os("OUT_DIR").expect("OUT_DIR should be set by cargo");
+    write(&out_dir, "space.rs", &generate_space(mask, &spaces));
+    write(
+        &out_dir,
+        "status.rs",
+        &generate_status(mask, &spaces, &statuses, &since, &categories),
+    );
+}
+
+/// Orders the defines by their value, rejecting duplicate values.
+fn by_value(defines: Vec<Define>) -> BTreeMap<u32, Define> {
+    let mut map = BTreeMap::new();
+
+    for define in defines {
+        if let Some(other) = map.insert(define.value, define) {
+            panic!("{} has a duplicate value: {:#06x}", other.name, other.value);
+        }
+    }
+
+    map
+}
+
+/// Generates the `Space` enum.
+///
+/// The space mask is not a space itself and is therefore emitted as the `MASK` constant.
+fn generate_space(mask: u32, spaces: &BTreeMap<u32, Define>) -> String {
+    let mut code = String::new();
+    write_enum(&mut code, SPACE_ATTRIBUTES, "Space", "space", spaces);
+    code.push_str("\nimpl Space {\n");
+    writeln!(
+        code,
+        "    /// Mask of the bits of a status denoting its space.\n    pub const MASK: u32 = {mask:#06X};\n"
+    )
+    .expect("writing to a string cannot fail");
+    write_as_str(&mut code, "Space", spaces);
+    code.push('\n');
+    write_description(&mut code, "space", spaces);
+    code.push_str("}\n");
+    code
+}
+
+/// Reads a table of per-status values, warning about entries of unknown statuses.
+fn read_table<T>(
+    path: &str,
+    statuses: &BTreeMap<u32, Define>,
+    parse: impl Fn(&str, &str) -> T,
+) -> BTreeMap<String, T> {
+    let table =
+        fs::read_to_string(path).unwrap_or_else(|error| panic!("cannot read {path}: {error}"));
+    let mut values = BTreeMap::new();
+
+    for line in table.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (name, value) = line
+            .split_once(char::is_whitespace)
+            .unwrap_or_else(|| panic!("{path} has an invalid line: {line}"));
+
+        if !statuses.values().any(|status| status.name == name) {
+            println!("cargo::warning={name} is listed in {path}, but not defined in {HEADER}");
+        }
+
+        values.insert(name.to_owned(), parse(name, value.trim()));
+    }
+
+    values
+}
+
+/// Groups the statuses by their value in a table, failing for statuses missing from it.
+fn group_by<T: Clone + Ord>(
+    path: &str,
+    statuses: &BTreeMap<u32, Define>,
+    table: &BTreeMap<String, T>,
+) -> BTreeMap<T, Vec<String>> {
+    let mut groups = BTreeMap::<_, Vec<_>>::new();
+
+    for status in statuses.values() {
+        let value = table
+            .get(&status.name)
+            .unwrap_or_else(|| panic!("{} is missing from {path}", status.name));
+        groups
+            .entry(value.clone())
+            .or_default()
+            .push(format!("Self::{}", status.variant));
+    }
+
+    groups
+}
+
+/// Generates the `Status` enum.
+fn generate_status(
+    mask: u32,
+    spaces: &BTreeMap<u32, Define>,
+    statuses: &BTreeMap<u32, Define>,
+    since: &BTreeMap<String, (u16, u8)>,
+    categories: &BTreeMap<String, String>,
+) -> String {
+    let mut code = String::new();
+    write_enum(&mut code, STATUS_ATTRIBUTES, "Status", "status", statuses);
+    code.push_str("\nimpl Status {\n");
+    write_space(&mut code, mask, spaces, statuses);
+    code.push('\n');
+    write_since_sdk(&mut code, statuses, since);
+    code.push('\n');
+    write_category(&mut code, statuses, categories);
+    code.push('\n');
+    code.push_str("    #[expect(clippy::too_many_lines)]\n");
+    write_as_str(&mut code, "status", statuses);
+    code.push('\n');
+    code.push_str("    #[expect(clippy::too_many_lines)]\n");
+    write_description(&mut code, "status", statuses);
+    code.push_str("}\n");
+    code
+}
+
+/// Writes the enum declaration.
+fn write_enum(
+    code: &mut String,
+    attributes: &str,
+    name: &str,
+    noun: &str,
+    defines: &BTreeMap<u32, Define>,
+) {
+    writeln!(
+        code,
+        "sdk_enum! {{\n    prefix: {PREFIX:?},\n    noun: {noun:?},\n    repr: u32,\n"
+    )
+    .expect("writing to a string cannot fail");
+    code.push_str(attributes);
+    writeln!(code, "    pub enum {name} {{").expect("writing to a string cannot fail");
+
+    for define in defines.values() {
+        writeln!(
+            code,
+            "        #[doc = {:?}]\n        {} = {:#06X},",
+            doc(&define.description),
+            define.variant,
+            define.value
+        )
+        .expect("writing to a string cannot fail");
+    }
+
+    code.push_str("    }\n}\n");
+}
+
+/// Writes the `space()` method, mapping each status to the space of its upper byte.
+fn write_space(
+    code: &mut String,
+    mask: u32,
+    spaces: &BTreeMap<u32, Define>,
+    statuses: &BTreeMap<u32, Define>,
+) {
+    code.push_str(
+        "    /// Returns the space the status belongs to.\n    #[must_use]\n    pub const fn space(self) -> Space {\n        match self {\n",
+    );
+
+    for space in spaces.values() {
+        let members: Vec<_> = statuses
+            .values()
+            .filter(|status| status.value & mask == space.value)
+            .map(|status| format!("Self::{}", status.variant))
+            .collect();
+
+        if !members.is_empty() {
+            writeln!(
+                code,
+                "            {} => Space::{},",
+                members.join(" | "),
+                space.variant
+            )
+            .expect("writing to a string cannot fail");
+        }
+    }
+
+    for status in statuses.values() {
+        assert!(
+            spaces.contains_key(&(status.value & mask)),
+            "{} does not belong to a known space",
+            status.name
+        );
+    }
+
+    code.push_str("        }\n    }\n");
+}
+
+/// Writes the `since_sdk()` method, grouping the statuses by the SDK release defining them.
+fn write_since_sdk(
+    code: &mut String,
+    statuses: &BTreeMap<u32, Define>,
+    since: &BTreeMap<String, (u16, u8)>,
+) {
+    code.push_str(
+        "    /// Returns the first SDK release defining the status.\n    #[must_use]\n    pub const fn since_sdk(self) -> SdkVersion {\n        match self {\n",
+    );
+
+    for ((major, minor), members) in group_by(SINCE_TABLE, statuses, since) {
+        writeln!(
+            code,
+            "            {} => SdkVersion::new({major}, {minor}),",
+            members.join(" | ")
+        )
+        .expect("writing to a string cannot fail");
+    }
+
+    code.push_str("        }\n    }\n");
+}
+
+/// Writes the `category()` method, grouping the statuses by their category.
+fn write_category(
+    code: &mut String,
+    statuses: &BTreeMap<u32, Define>,
+    categories: &BTreeMap<String, String>,
+) {
+    code.push_str(
+        "    /// Returns the category of the status.\n    #[must_use]\n    pub const fn category(self) -> Category {\n        match self {\n",
+    );
+
+    for (category, members) in group_by(CATEGORY_TABLE, statuses, categories) {
+        writeln!(
+            code,
+            "            {} => Category::{category},",
+            members.join(" | ")
+        )
+        .expect("writing to a string cannot fail");
+    }
+
+    code.push_str("        }\n    }\n");
+}
+
+/// Writes the `as_str()` method.
+fn write_as_str(code: &mut String, noun: &str, defines: &BTreeMap<u32, Define>) {
+    writeln!(
+        code,
+        "    /// Return the name of the {noun}.\n    #[must_use]\n    pub const fn as_str(self) -> &'static str {{\n        match self {{"
+    )
+    .expect("writing to a string cannot fail");
+
+    for define in defines.values() {
+        writeln!(
+            code,
+            "            Self::{} => {:?},",
+            define.variant, define.name
+        )
+        .expect("writing to a string cannot fail");
+    }
+
+    code.push_str("        }\n    }\n");
+}
+
+/// Writes the `description()` method.
+fn write_description(code: &mut String, noun: &str, defines: &BTreeMap<u32, Define>) {
+    writeln!(
+        code,
+        "    /// Return a human-readable description of the {noun}.\n    #[must_use]\n    pub const fn description(self) -> &'static str {{\n        match self {{"
+    )
+    .expect("writing to a string cannot fail");
+
+    for define in defines.values() {
+        writeln!(
+            code,
+            "            Self::{} => {:?},",
+            define.variant, define.description
+        )
+        .expect("writing to a string cannot fail");
+    }
+
+    code.push_str("        }\n    }\n");
+}
+
+/// Writes the generated code to a file in `out_dir`.
+fn write(out_dir: impl AsRef<Path>, file_name: &str, code: &str) {
+    let path = out_dir.as_ref().join(file_name);
+    fs::write(&path, code)
+        .unwrap_or_else(|error| panic!("cannot write {}: {error}", path.display()));
+}
+
+/// Parses an SDK version of the form `<major>.<minor>`.
+fn parse_version(name: &str, version: &str) -> (u16, u8) {
+    version
+        .split_once('.')
+        .and_then(|(major, minor)| Some((major.parse().ok()?, minor.parse().ok()?)))
+        .unwrap_or_else(|| panic!("{name} has an invalid SDK version: {version}"))
+}
+
+/// Returns the variant name of an SDK name.
+fn variant_name(name: &str) -> String {
+    if let Some((_, variant)) = VARIANT_NAMES.iter().find(|(sdk_name, _)| *sdk_name == name) {
+        return (*variant).to_owned();
+    }
+
+    let name = name.trim_start_matches(PREFIX);
+    name.strip_suffix(SPACE_SUFFIX)
+        .unwrap_or(name)
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars.next().map_or_else(String::new, |first| {
+                format!(
+                    "{}{}",
+                    first.to_ascii_uppercase(),
+                    chars.as_str().to_ascii_lowercase()
+                )
+            })
+        })
+        .collect()
+}
+
+/// Converts a description into documentation, quoting identifiers in backticks.
+fn doc(description: &str) -> String {
+    description
+        .split(' ')
+        .map(|word| {
+            let start = word
+                .find(|char: char| char.is_ascii_alphanumeric())
+                .unwrap_or(word.len());
+            let end = word
+                .rfind(|char: char| char.is_ascii_alphanumeric())
+                .map_or(start, |index| index + 1);
+            let (head, rest) = word.split_at(start);
+            let (identifier, tail) = rest.split_at(end - start);
+
+            if is_identifier(identifier) {
+                format!("{head}`{identifier}`{tail}")
+            } else {
+                word.to_owned()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Determines whether a word is an identifier which needs to be quoted in documentation.
+fn is_identifier(word: &str) -> bool {
+    word.chars()
+        .all(|char| char.is_ascii_alphanumeric() || char == '_')
+        && (word.contains('_')
+            || (word.chars().any(|char| char.is_ascii_lowercase())
+                && word.chars().skip(1).any(|char| char.is_ascii_uppercase())))
+}
@@ -1,7 +1,23 @@
 //! Library for common data structures used across platforms.
 #![no_std]
 
-pub use self::status::{Space, Status};
+#[cfg(feature = "std")]
+extern crate std;
 
+#[cfg(all(test, not(feature = "serde")))]
+use serde_test as _;
+
+pub use self::parse_name_error::ParseNameError;
+pub use self::sdk_version::SdkVersion;
+pub use self::status::{Category, RawStatus, Space, Status, StatusCounters};
+
+/// Result type with [`Status`] as error variant.
+pub type Result<T> = core::result::Result<T, Status>;
+
+mod macros;
+mod parse_name_error;
+#[cfg(feature = "serde")]
+pub mod repr;
+mod sdk_version;
 mod status;
 pub mod zigbee;
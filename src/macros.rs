@@ -0,0 +1,175 @@
+//! Macros used to declare the enumerations of this crate.
+
+/// Declares an enumeration of SDK values along with the list of all its variants.
+///
+/// The enumeration is represented by `repr` and its variants must be declared in strictly
+/// ascending order of their discriminants, which is asserted at compile time.
+///
+/// The enumeration must provide `as_str()` returning the SDK name of a value, whose common prefix
+/// is `prefix`. The macro implements parsing and formatting of the SDK names, hexadecimal
+/// formatting and the optional `serde`, `defmt` and `le-stream` support based thereon.
+/// `noun` names the kind of value in messages and documentation.
+macro_rules! sdk_enum {
+    (
+        prefix: $prefix:literal,
+        noun: $noun:literal,
+        repr: $repr:ident,
+
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident = $value:literal,
+            )+
+        }
+    ) => {
+        $(#[$meta])*
+        #[repr($repr)]
+        $vis enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant = $value,
+            )+
+        }
+
+        impl $name {
+            /// All known values in ascending order.
+            pub const ALL: &[Self] = &[$(Self::$variant,)+];
+
+            /// Returns an iterator over all known values in ascending order.
+            pub fn iter() -> ::core::iter::Copied<::core::slice::Iter<'static, Self>> {
+                Self::ALL.iter().copied()
+            }
+
+            #[doc = concat!("Parses the ", $noun, " from its SDK name, ignoring case.")]
+            ///
+            #[doc = concat!(
+                "Unlike [`FromStr`](::core::str::FromStr), this also accepts names without the ",
+                "common `",
+                $prefix,
+                "` prefix."
+            )]
+            ///
+            /// # Errors
+            ///
+            #[doc = concat!(
+                "Returns a [`ParseNameError`](crate::ParseNameError) if the name does not denote ",
+                "a known ",
+                $noun,
+                "."
+            )]
+            pub fn from_str_ignore_case(
+                name: &str,
+            ) -> Result<Self, $crate::parse_name_error::ParseNameError> {
+                $crate::parse_name_error::parse_ignore_case(name, $prefix, $noun)
+            }
+        }
+
+        const _: () = {
+            let values = [$($name::$variant as u64,)+];
+            let mut index = 1;
+
+            while index < values.len() {
+                assert!(
+                    values[index - 1] < values[index],
+                    concat!(
+                        "the variants of `",
+                        stringify!($name),
+                        "` must be declared in strictly ascending order"
+                    )
+                );
+                index += 1;
+            }
+        };
+
+        impl ::core::str::FromStr for $name {
+            type Err = $crate::parse_name_error::ParseNameError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::iter()
+                    .find(|value| value.as_str() == s)
+                    .ok_or($crate::parse_name_error::ParseNameError::new($noun))
+            }
+        }
+
+        impl ::core::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                write!(f, "{}", self.as_str())
+            }
+        }
+
+        impl ::core::fmt::LowerHex for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                write!(
+                    f,
+                    "{:#0width$x}",
+                    *self as $repr,
+                    width = 2 + 2 * size_of::<$repr>()
+                )
+            }
+        }
+
+        impl ::core::fmt::UpperHex for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                write!(
+                    f,
+                    "{:#0width$X}",
+                    *self as $repr,
+                    width = 2 + 2 * size_of::<$repr>()
+                )
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl $crate::repr::Representable for $name {
+            type Code = $repr;
+
+            fn code(self) -> Self::Code {
+                self as Self::Code
+            }
+
+            fn name(self) -> &'static str {
+                self.as_str()
+            }
+
+            fn from_code(code: Self::Code) -> Option<Self> {
+                <Self as ::num_traits::FromPrimitive>::from_u64(code.into())
+            }
+
+            fn from_name(name: &str) -> Option<Self> {
+                Self::from_str_ignore_case(name).ok()
+            }
+        }
+
+        #[cfg(feature = "defmt")]
+        impl ::defmt::Format for $name {
+            fn format(&self, f: ::defmt::Formatter<'_>) {
+                ::defmt::write!(f, "{=str}", self.as_str());
+            }
+        }
+
+        #[cfg(feature = "le-stream")]
+        #[doc = concat!("Encoded as `", stringify!($repr), "`. Unknown values are rejected.")]
+        impl ::le_stream::FromLeStream for $name {
+            fn from_le_stream<T>(bytes: T) -> Option<Self>
+            where
+                T: Iterator<Item = u8>,
+            {
+                <$repr as ::le_stream::FromLeStream>::from_le_stream(bytes).and_then(|value| {
+                    <Self as ::num_traits::FromPrimitive>::from_u64(value.into())
+                })
+            }
+        }
+
+        #[cfg(feature = "le-stream")]
+        impl ::le_stream::ToLeStream for $name {
+            type Iter = <$repr as ::le_stream::ToLeStream>::Iter;
+
+            fn to_le_stream(self) -> Self::Iter {
+                ::le_stream::ToLeStream::to_le_stream(self as $repr)
+            }
+        }
+    };
+}
+
+pub(crate) use sdk_enum;
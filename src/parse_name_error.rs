@@ -0,0 +1,142 @@
+use core::error::Error;
+use core::fmt::{self, Display};
+use core::str::FromStr;
+
+/// Maximum length of a name accepted by the case-insensitive parsers.
+const MAX_NAME_LEN: usize = 128;
+
+/// Error returned when parsing an SDK name fails.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ParseNameError {
+    type_name: &'static str,
+}
+
+impl ParseNameError {
+    /// Creates a new `ParseNameError`.
+    pub(crate) const fn new(type_name: &'static str) -> Self {
+        Self { type_name }
+    }
+
+    /// Returns the name of the type that was attempted to be parsed.
+    #[must_use]
+    pub const fn type_name(self) -> &'static str {
+        self.type_name
+    }
+}
+
+impl Display for ParseNameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown {} name", self.type_name)
+    }
+}
+
+impl Error for ParseNameError {}
+
+/// Parses an SDK name case-insensitively, with or without its common `prefix`.
+///
+/// # Errors
+///
+/// Returns a [`ParseNameError`] if the name does not denote a known value.
+pub fn parse_ignore_case<T>(
+    name: &str,
+    prefix: &str,
+    type_name: &'static str,
+) -> Result<T, ParseNameError>
+where
+    T: FromStr<Err = ParseNameError>,
+{
+    let mut buffer = [0; MAX_NAME_LEN];
+    normalize(name.trim(), prefix, &mut buffer)
+        .ok_or_else(|| ParseNameError::new(type_name))?
+        .parse()
+}
+
+/// Writes the upper-case `name` into `buffer`, prepending `prefix` if it is missing.
+fn normalize<'buf>(name: &str, prefix: &str, buffer: &'buf mut [u8]) -> Option<&'buf str> {
+    let prefix = if name
+        .as_bytes()
+        .get(..prefix.len())
+        .is_some_and(|head| head.eq_ignore_ascii_case(prefix.as_bytes()))
+    {
+        ""
+    } else {
+        prefix
+    };
+
+    let target = buffer.get_mut(..prefix.len() + name.len())?;
+    let (head, tail) = target.split_at_mut(prefix.len());
+    head.copy_from_slice(prefix.as_bytes());
+    tail.copy_from_slice(name.as_bytes());
+    tail.make_ascii_uppercase();
+    core::str::from_utf8(target).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MAX_NAME_LEN, ParseNameError, parse_ignore_case};
+    use crate::Status;
+
+    fn parse(name: &str) -> Result<Status, ParseNameError> {
+        parse_ignore_case(name, "SL_STATUS_", "status")
+    }
+
+    #[test]
+    fn with_prefix() {
+        assert_eq!(
+            parse("SL_STATUS_INVALID_PARAMETER"),
+            Ok(Status::InvalidParameter)
+        );
+        assert_eq!(
+            parse("sl_status_invalid_parameter"),
+            Ok(Status::InvalidParameter)
+        );
+        assert_eq!(
+            parse("Sl_Status_Invalid_Parameter"),
+            Ok(Status::InvalidParameter)
+        );
+    }
+
+    #[test]
+    fn without_prefix() {
+        assert_eq!(parse("INVALID_PARAMETER"), Ok(Status::InvalidParameter));
+        assert_eq!(parse("invalid_parameter"), Ok(Status::InvalidParameter));
+    }
+
+    #[test]
+    fn surrounding_whitespace() {
+        assert_eq!(parse("  invalid_parameter\n"), Ok(Status::InvalidParameter));
+    }
+
+    #[test]
+    fn partial_prefix() {
+        assert_eq!(
+            parse("STATUS_INVALID_PARAMETER"),
+            Err(ParseNameError::new("status"))
+        );
+        assert_eq!(parse("SL_STATUS_"), Err(ParseNameError::new("status")));
+        assert_eq!(parse(""), Err(ParseNameError::new("status")));
+    }
+
+    #[test]
+    fn length() {
+        let mut buffer = [b' '; MAX_NAME_LEN + 1];
+        buffer[..9].copy_from_slice(b"NOT_FOUND");
+        let name = core::str::from_utf8(&buffer).expect("The name is ASCII.");
+        assert_eq!(parse(name), Ok(Status::NotFound));
+
+        buffer[MAX_NAME_LEN] = b'X';
+        let name = core::str::from_utf8(&buffer).expect("The name is ASCII.");
+        assert_eq!(parse(name), Err(ParseNameError::new("status")));
+
+        let buffer = [b'A'; MAX_NAME_LEN + 1];
+        let name = core::str::from_utf8(&buffer).expect("The name is ASCII.");
+        assert_eq!(parse(name), Err(ParseNameError::new("status")));
+    }
+
+    #[test]
+    fn error() {
+        let error = parse("UNKNOWN").unwrap_err();
+        assert_eq!(error.type_name(), "status");
+    }
+}
@@ -0,0 +1,196 @@
+//! Alternative `serde` representations of the enumerations in this crate.
+//!
+//! By default, the enumerations are (de)serialized by their Rust variant names.
+//! The modules herein can be used with `#[serde(with = "...")]` to select another representation:
+//!
+//! * [`code`] uses the numeric code, e.g. `33`.
+//! * [`name`] uses the SDK name, e.g. `"SL_STATUS_INVALID_PARAMETER"`.
+//! * [`code_and_name`] uses a struct holding both, e.g.
+//!   `{"code": 33, "name": "SL_STATUS_INVALID_PARAMETER"}`.
+//!
+//! When deserializing from human-readable formats, all modules accept any of the above forms.
+//! Non-human-readable formats can only be deserialized in the form they were serialized in.
+
+use core::fmt::Debug;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+pub mod code;
+pub mod code_and_name;
+pub mod name;
+mod visitor;
+
+/// Enumerations that have a numeric code and an SDK name.
+pub trait Representable: Copy + Sized {
+    /// The type of the numeric code.
+    type Code: Copy + Debug + Into<u64> + TryFrom<u64> + Serialize + DeserializeOwned;
+
+    /// Returns the numeric code.
+    fn code(self) -> Self::Code;
+
+    /// Returns the SDK name.
+    fn name(self) -> &'static str;
+
+    /// Returns the value with the given numeric code, if any.
+    fn from_code(code: Self::Code) -> Option<Self>;
+
+    /// Returns the value with the given SDK name, if any.
+    fn from_name(name: &str) -> Option<Self>;
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+    use serde_test::{
+        Configure, Readable, Token, assert_de_tokens, assert_de_tokens_error, assert_tokens,
+    };
+
+    use crate::Status;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Code(#[serde(with = "super::code")] Status);
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Name(#[serde(with = "super::name")] Status);
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct CodeAndName(#[serde(with = "super::code_and_name")] Status);
+
+    const fn struct_tokens(newtype: &'static str) -> [Token; 7] {
+        [
+            Token::NewtypeStruct { name: newtype },
+            Token::Struct {
+                name: "CodeAndName",
+                len: 2,
+            },
+            Token::Str("code"),
+            Token::U32(0x0021),
+            Token::Str("name"),
+            Token::Str("SL_STATUS_INVALID_PARAMETER"),
+            Token::StructEnd,
+        ]
+    }
+
+    #[test]
+    fn code_round_trip() {
+        assert_tokens(
+            &Code(Status::InvalidParameter).compact(),
+            &[Token::NewtypeStruct { name: "Code" }, Token::U32(0x0021)],
+        );
+    }
+
+    #[test]
+    fn name_round_trip() {
+        assert_tokens(
+            &Name(Status::InvalidParameter).compact(),
+            &[
+                Token::NewtypeStruct { name: "Name" },
+                Token::Str("SL_STATUS_INVALID_PARAMETER"),
+            ],
+        );
+    }
+
+    #[test]
+    fn code_and_name_round_trip() {
+        assert_tokens(
+            &CodeAndName(Status::InvalidParameter).compact(),
+            &struct_tokens("CodeAndName"),
+        );
+    }
+
+    #[test]
+    fn human_readable_accepts_all_representations() {
+        let expected = Code(Status::InvalidParameter).readable();
+        let newtype = Token::NewtypeStruct { name: "Code" };
+
+        assert_de_tokens(&expected, &[newtype, Token::U32(0x0021)]);
+        assert_de_tokens(&expected, &[newtype, Token::I64(0x0021)]);
+        assert_de_tokens(
+            &expected,
+            &[newtype, Token::Str("SL_STATUS_INVALID_PARAMETER")],
+        );
+        assert_de_tokens(&expected, &[newtype, Token::Str("invalid_parameter")]);
+        assert_de_tokens(&expected, &struct_tokens("Code"));
+    }
+
+    #[test]
+    fn struct_with_either_field() {
+        let expected = CodeAndName(Status::InvalidParameter).readable();
+        let newtype = Token::NewtypeStruct {
+            name: "CodeAndName",
+        };
+
+        assert_de_tokens(
+            &expected,
+            &[
+                newtype,
+                Token::Map { len: Some(1) },
+                Token::Str("code"),
+                Token::U32(0x0021),
+                Token::MapEnd,
+            ],
+        );
+        assert_de_tokens(
+            &expected,
+            &[
+                newtype,
+                Token::Map { len: Some(1) },
+                Token::Str("name"),
+                Token::Str("SL_STATUS_INVALID_PARAMETER"),
+                Token::MapEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn struct_with_mismatching_fields() {
+        assert_de_tokens_error::<Readable<CodeAndName>>(
+            &[
+                Token::NewtypeStruct {
+                    name: "CodeAndName",
+                },
+                Token::Map { len: Some(2) },
+                Token::Str("code"),
+                Token::U32(0x0021),
+                Token::Str("name"),
+                Token::Str("SL_STATUS_FAIL"),
+                Token::MapEnd,
+            ],
+            "code and name denote different values",
+        );
+    }
+
+    #[test]
+    fn struct_without_fields() {
+        assert_de_tokens_error::<Readable<CodeAndName>>(
+            &[
+                Token::NewtypeStruct {
+                    name: "CodeAndName",
+                },
+                Token::Map { len: Some(0) },
+                Token::MapEnd,
+            ],
+            "missing field `code`",
+        );
+    }
+
+    #[test]
+    fn unknown_code() {
+        assert_de_tokens_error::<Readable<Code>>(
+            &[Token::NewtypeStruct { name: "Code" }, Token::U32(0xFFFF)],
+            "invalid value: integer `65535`, expected a known numeric code, SDK name or struct of both",
+        );
+    }
+
+    #[test]
+    fn unknown_name() {
+        assert_de_tokens_error::<Readable<Name>>(
+            &[
+                Token::NewtypeStruct { name: "Name" },
+                Token::Str("SL_STATUS_UNKNOWN"),
+            ],
+            "invalid value: string \"SL_STATUS_UNKNOWN\", expected a known numeric code, SDK name or struct of both",
+        );
+    }
+}
@@ -0,0 +1,39 @@
+//! Represent enumerations by their numeric code.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::Representable;
+use super::visitor::Visitor;
+
+/// Serializes the value by its numeric code.
+///
+/// # Errors
+///
+/// Returns an error if the serializer fails.
+pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Representable,
+    S: Serializer,
+{
+    value.code().serialize(serializer)
+}
+
+/// Deserializes the value from its numeric code.
+///
+/// Human-readable formats also accept the other representations.
+///
+/// # Errors
+///
+/// Returns an error if the input does not denote a known value.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: Representable,
+    D: Deserializer<'de>,
+{
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(Visitor::new())
+    } else {
+        let code = T::Code::deserialize(deserializer)?;
+        T::from_code(code).ok_or_else(|| Visitor::<T>::invalid_code(code.into()))
+    }
+}
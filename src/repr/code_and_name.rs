@@ -0,0 +1,43 @@
+//! Represent enumerations by a struct holding both their numeric code and their SDK name.
+
+use serde::ser::SerializeStruct;
+use serde::{Deserializer, Serializer};
+
+use super::Representable;
+use super::visitor::{FIELDS, STRUCT_NAME, Visitor};
+
+/// Serializes the value as a struct of its numeric code and SDK name.
+///
+/// # Errors
+///
+/// Returns an error if the serializer fails.
+pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Representable,
+    S: Serializer,
+{
+    let mut state = serializer.serialize_struct(STRUCT_NAME, FIELDS.len())?;
+    state.serialize_field("code", &value.code())?;
+    state.serialize_field("name", value.name())?;
+    state.end()
+}
+
+/// Deserializes the value from a struct of its numeric code and SDK name.
+///
+/// Either field may be omitted, but if both are present, they must denote the same value.
+/// Human-readable formats also accept the other representations.
+///
+/// # Errors
+///
+/// Returns an error if the input does not denote a known value.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: Representable,
+    D: Deserializer<'de>,
+{
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(Visitor::new())
+    } else {
+        deserializer.deserialize_struct(STRUCT_NAME, FIELDS, Visitor::new())
+    }
+}
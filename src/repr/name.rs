@@ -0,0 +1,38 @@
+//! Represent enumerations by their SDK name.
+
+use serde::{Deserializer, Serializer};
+
+use super::Representable;
+use super::visitor::Visitor;
+
+/// Serializes the value by its SDK name.
+///
+/// # Errors
+///
+/// Returns an error if the serializer fails.
+pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Representable,
+    S: Serializer,
+{
+    serializer.serialize_str(value.name())
+}
+
+/// Deserializes the value from its SDK name.
+///
+/// Human-readable formats also accept the other representations.
+///
+/// # Errors
+///
+/// Returns an error if the input does not denote a known value.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: Representable,
+    D: Deserializer<'de>,
+{
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(Visitor::new())
+    } else {
+        deserializer.deserialize_str(Visitor::new())
+    }
+}
@@ -0,0 +1,159 @@
+use core::fmt::{self, Formatter};
+use core::marker::PhantomData;
+
+use serde::de::{self, DeserializeSeed, Error, IgnoredAny, MapAccess, SeqAccess, Unexpected};
+use serde::{Deserialize, Deserializer};
+
+use super::Representable;
+
+/// Name of the struct used by the [`code_and_name`](super::code_and_name) representation.
+pub const STRUCT_NAME: &str = "CodeAndName";
+
+/// Fields of the struct used by the [`code_and_name`](super::code_and_name) representation.
+pub const FIELDS: &[&str] = &["code", "name"];
+
+/// Visitor accepting all representations of a [`Representable`].
+pub struct Visitor<T>(PhantomData<T>);
+
+impl<T> Visitor<T>
+where
+    T: Representable,
+{
+    /// Creates a new visitor.
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+
+    /// Returns an error for an unknown numeric code.
+    pub fn invalid_code<E>(code: u64) -> E
+    where
+        E: Error,
+    {
+        E::invalid_value(Unexpected::Unsigned(code), &Self::new())
+    }
+
+    /// Returns the value with the given numeric code.
+    fn from_code<E>(code: u64) -> Result<T, E>
+    where
+        E: Error,
+    {
+        T::Code::try_from(code)
+            .ok()
+            .and_then(T::from_code)
+            .ok_or_else(|| Self::invalid_code(code))
+    }
+
+    /// Returns the value with the given SDK name.
+    fn from_name<E>(name: &str) -> Result<T, E>
+    where
+        E: Error,
+    {
+        T::from_name(name).ok_or_else(|| E::invalid_value(Unexpected::Str(name), &Self::new()))
+    }
+
+    /// Combines the optional fields of the struct representation.
+    fn from_fields<E>(code: Option<T::Code>, name: Option<T>) -> Result<T, E>
+    where
+        E: Error,
+    {
+        let by_code = code.map(|code| Self::from_code(code.into())).transpose()?;
+
+        match (by_code, name) {
+            (Some(by_code), Some(by_name))
+                if Into::<u64>::into(by_code.code()) != by_name.code().into() =>
+            {
+                Err(E::custom("code and name denote different values"))
+            }
+            (Some(value), _) | (None, Some(value)) => Ok(value),
+            (None, None) => Err(E::missing_field("code")),
+        }
+    }
+}
+
+impl<'de, T> de::Visitor<'de> for Visitor<T>
+where
+    T: Representable,
+{
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a known numeric code, SDK name or struct of both")
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Self::from_code(v)
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        u64::try_from(v).map_or_else(
+            |_| Err(E::invalid_value(Unexpected::Signed(v), &self)),
+            Self::from_code,
+        )
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Self::from_name(v)
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut code = None;
+        let mut name = None;
+
+        while let Some(field) = map.next_key::<Field>()? {
+            match field {
+                Field::Code => code = Some(map.next_value()?),
+                Field::Name => name = Some(map.next_value_seed(Self::new())?),
+                Field::Other => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+
+        Self::from_fields(code, name)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let code = seq.next_element()?;
+        let name = seq.next_element_seed(Self::new())?;
+        Self::from_fields(code, name)
+    }
+}
+
+impl<'de, T> DeserializeSeed<'de> for Visitor<T>
+where
+    T: Representable,
+{
+    type Value = T;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(self)
+    }
+}
+
+/// Fields of the struct representation.
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum Field {
+    Code,
+    Name,
+    #[serde(other)]
+    Other,
+}
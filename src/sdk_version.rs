@@ -0,0 +1,40 @@
+use core::fmt::{self, Display};
+
+/// A release of the Silicon Labs SDK.
+///
+/// Releases of the Gecko SDK are numbered as `<major>.<minor>`, e.g. `4.4`, whereas releases of
+/// its successor, the Simplicity SDK, are numbered as `<year>.<month>`, e.g. `2024.6`.
+/// Hence versions of both compare in order of their release.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Ord, PartialOrd)]
+pub struct SdkVersion {
+    major: u16,
+    minor: u8,
+}
+
+impl SdkVersion {
+    /// Creates a new `SdkVersion`.
+    #[must_use]
+    pub const fn new(major: u16, minor: u8) -> Self {
+        Self { major, minor }
+    }
+
+    /// Returns the major version, or the year of a Simplicity SDK release.
+    #[must_use]
+    pub const fn major(self) -> u16 {
+        self.major
+    }
+
+    /// Returns the minor version, or the month of a Simplicity SDK release.
+    #[must_use]
+    pub const fn minor(self) -> u8 {
+        self.minor
+    }
+}
+
+impl Display for SdkVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
@@ -0,0 +1,28 @@
+/// Categories of status codes, describing how a caller should react to them.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Ord, PartialOrd)]
+pub enum Category {
+    /// The operation succeeded.
+    Success,
+
+    /// The status reports an event or a state change rather than a failure.
+    Informational,
+
+    /// The condition is temporary and is expected to clear by itself.
+    ///
+    /// The operation may be retried once the condition has cleared.
+    Transient,
+
+    /// The operation failed, but may succeed if it is retried as is.
+    Retryable,
+
+    /// The request was invalid, which indicates a bug or misconfiguration in the caller.
+    Configuration,
+
+    /// A hardware, flash or storage failure occurred.
+    Hardware,
+
+    /// The operation failed and retrying it will not help.
+    Fatal,
+}
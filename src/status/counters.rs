@@ -0,0 +1,178 @@
+use super::{RawStatus, Status};
+
+#[cfg(feature = "serde")]
+mod counts;
+
+/// Number of known status codes.
+const LEN: usize = Status::ALL.len();
+
+/// Counters of how often each status occurred.
+///
+/// Every known [`Status`] has its own counter, whereas all unknown status codes share a
+/// common counter. All counters saturate instead of overflowing.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "counts::Counters"))]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct StatusCounters {
+    #[cfg_attr(feature = "serde", serde(serialize_with = "counts::serialize"))]
+    counts: [u64; LEN],
+    unknown: u64,
+}
+
+impl StatusCounters {
+    /// Creates new counters, all of which are zero.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            counts: [0; LEN],
+            unknown: 0,
+        }
+    }
+
+    /// Increments the counter of the given status.
+    pub fn increment<T>(&mut self, status: T)
+    where
+        T: Into<RawStatus>,
+    {
+        let counter = match status.into().status() {
+            Ok(status) => &mut self.counts[index(status)],
+            Err(_) => &mut self.unknown,
+        };
+        *counter = counter.saturating_add(1);
+    }
+
+    /// Adds the counters of `other` to these counters.
+    pub fn merge(&mut self, other: &Self) {
+        for (count, other) in self.counts.iter_mut().zip(other.counts) {
+            *count = count.saturating_add(other);
+        }
+
+        self.unknown = self.unknown.saturating_add(other.unknown);
+    }
+
+    /// Resets all counters to zero.
+    pub const fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Returns how often the given status occurred.
+    #[must_use]
+    pub fn count(&self, status: Status) -> u64 {
+        self.counts[index(status)]
+    }
+
+    /// Returns how often unknown status codes occurred.
+    #[must_use]
+    pub const fn unknown(&self) -> u64 {
+        self.unknown
+    }
+
+    /// Returns how often any status occurred.
+    #[must_use]
+    pub fn total(&self) -> u64 {
+        self.counts
+            .iter()
+            .fold(self.unknown, |total, count| total.saturating_add(*count))
+    }
+
+    /// Returns an iterator over the known statuses which occurred and their counts.
+    ///
+    /// The statuses are yielded in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = (Status, u64)> {
+        Status::iter()
+            .zip(self.counts)
+            .filter(|(_, count)| *count > 0)
+    }
+
+    /// Returns an iterator over the known statuses which occurred and their counts.
+    ///
+    /// The statuses are yielded from the most to the least frequent one.
+    /// Statuses which occurred equally often are yielded in ascending order.
+    pub fn by_frequency(&self) -> impl Iterator<Item = (Status, u64)> {
+        let mut indices: [u16; LEN] = core::array::from_fn(|index| {
+            u16::try_from(index)
+                .unwrap_or_else(|_| unreachable!("There are less than 2^16 statuses."))
+        });
+        indices.sort_unstable_by(|&lhs, &rhs| {
+            self.counts[usize::from(rhs)]
+                .cmp(&self.counts[usize::from(lhs)])
+                .then(lhs.cmp(&rhs))
+        });
+        indices
+            .into_iter()
+            .map(|index| {
+                (
+                    Status::ALL[usize::from(index)],
+                    self.counts[usize::from(index)],
+                )
+            })
+            .take_while(|(_, count)| *count > 0)
+    }
+}
+
+impl Default for StatusCounters {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the index of the counter of the given status.
+fn index(status: Status) -> usize {
+    Status::ALL
+        .binary_search(&status)
+        .unwrap_or_else(|_| unreachable!("All statuses are contained in Status::ALL."))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RawStatus, Status, StatusCounters};
+
+    #[test]
+    fn by_frequency() {
+        let mut counters = StatusCounters::new();
+
+        for status in [
+            Status::Busy,
+            Status::Fail,
+            Status::Timeout,
+            Status::Busy,
+            Status::Fail,
+            Status::Busy,
+        ] {
+            counters.increment(status);
+        }
+
+        assert!(counters.by_frequency().eq([
+            (Status::Busy, 3),
+            (Status::Fail, 2),
+            (Status::Timeout, 1)
+        ]));
+    }
+
+    #[test]
+    fn by_frequency_ties_ascending() {
+        let mut counters = StatusCounters::new();
+
+        for status in [Status::Timeout, Status::Busy, Status::Fail] {
+            counters.increment(status);
+        }
+
+        assert!(counters.by_frequency().eq([
+            (Status::Fail, 1),
+            (Status::Busy, 1),
+            (Status::Timeout, 1)
+        ]));
+    }
+
+    #[test]
+    fn by_frequency_omits_unknown_and_zero() {
+        let mut counters = StatusCounters::new();
+        assert_eq!(counters.by_frequency().next(), None);
+
+        counters.increment(RawStatus::from(0xFFFF_FFFF));
+        assert_eq!(counters.by_frequency().next(), None);
+        assert_eq!(counters.unknown(), 1);
+        assert_eq!(counters.total(), 1);
+    }
+}
@@ -0,0 +1,107 @@
+//! Serialization of the per-status counters as a map of the status codes which occurred.
+
+use core::fmt::{self, Formatter};
+
+use serde::de::{MapAccess, Visitor};
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{LEN, Status, StatusCounters, index};
+use crate::repr::code;
+
+/// Serializes the counters as a map from status code to count, omitting zero counts.
+pub fn serialize<S>(counts: &[u64; LEN], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let occurred = || {
+        Status::iter()
+            .zip(counts.iter())
+            .filter(|(_, count)| **count > 0)
+    };
+    let mut map = serializer.serialize_map(Some(occurred().count()))?;
+
+    for (status, count) in occurred() {
+        map.serialize_entry(&Code(status), count)?;
+    }
+
+    map.end()
+}
+
+/// Deserialized form of the [`StatusCounters`].
+///
+/// The map of counts may contain status codes which are unknown to this version of the crate.
+/// Their counts are added to the count of unknown status codes.
+#[derive(Deserialize)]
+#[serde(rename = "StatusCounters")]
+pub struct Counters {
+    #[serde(deserialize_with = "deserialize")]
+    counts: Counts,
+    unknown: u64,
+}
+
+impl From<Counters> for StatusCounters {
+    fn from(counters: Counters) -> Self {
+        Self {
+            counts: counters.counts.known,
+            unknown: counters.unknown.saturating_add(counters.counts.unknown),
+        }
+    }
+}
+
+/// Counts of a map from status code to count.
+struct Counts {
+    known: [u64; LEN],
+    unknown: u64,
+}
+
+/// Key of the map of counts, which represents the status by its numeric code.
+struct Code(Status);
+
+impl Serialize for Code {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        code::serialize(&self.0, serializer)
+    }
+}
+
+/// Deserializes the counts from a map from status code to count.
+fn deserialize<'de, D>(deserializer: D) -> Result<Counts, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_map(CountsVisitor)
+}
+
+/// Visitor collecting the counts of a map into an array.
+struct CountsVisitor;
+
+impl<'de> Visitor<'de> for CountsVisitor {
+    type Value = Counts;
+
+    fn expecting(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a map from status code to count")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut counts = Counts {
+            known: [0; LEN],
+            unknown: 0,
+        };
+
+        while let Some((code, count)) = map.next_entry::<u32, u64>()? {
+            let counter = match Status::try_from(code) {
+                Ok(status) => &mut counts.known[index(status)],
+                Err(_) => &mut counts.unknown,
+            };
+            *counter = counter.saturating_add(count);
+        }
+
+        Ok(counts)
+    }
+}
@@ -0,0 +1,63 @@
+use std::io::{self, ErrorKind};
+
+use super::{Category, Status};
+
+impl Status {
+    /// Returns the [`ErrorKind`] which best describes the status.
+    ///
+    /// This mapping is best-effort. Statuses without a matching kind are mapped by their
+    /// [`Category`], falling back to [`ErrorKind::Other`].
+    #[must_use]
+    pub const fn io_error_kind(self) -> ErrorKind {
+        match self {
+            Self::Timeout
+            | Self::IoTimeout
+            | Self::MacIndirectTimeout
+            | Self::WifiConnectionTimeout
+            | Self::WifiFirmwareDownloadTimeout
+            | Self::BtL2capConnectionRequestTimeout
+            | Self::BtCtrlConnectionTimeout
+            | Self::BtCtrlConnectionAcceptTimeoutExceeded
+            | Self::BtCtrlLlResponseTimeout
+            | Self::BtCtrlAdvertisingTimeout => ErrorKind::TimedOut,
+            Self::WouldBlock => ErrorKind::WouldBlock,
+            Self::NotFound | Self::BtPsKeyNotFound | Self::BtAttAttNotFound => ErrorKind::NotFound,
+            Self::AlreadyExists
+            | Self::BtMeshAlreadyExists
+            | Self::BtCtrlAclConnectionAlreadyExists
+            | Self::BtMeshFoundationKeyIndexExists => ErrorKind::AlreadyExists,
+            Self::Permission => ErrorKind::PermissionDenied,
+            Self::Busy => ErrorKind::ResourceBusy,
+            Self::NotSupported | Self::NotAvailable => ErrorKind::Unsupported,
+            Self::AllocationFailed | Self::NoMoreResource => ErrorKind::OutOfMemory,
+            Self::Abort => ErrorKind::Interrupted,
+            Self::NetworkDown | Self::NotJoined => ErrorKind::NetworkDown,
+            Self::BtCtrlRemoteUserTerminated
+            | Self::BtCtrlRemoteDeviceTerminatedConnectionDueToLowResources => {
+                ErrorKind::ConnectionReset
+            }
+            Self::BtCtrlConnectionTerminatedByLocalHost => ErrorKind::ConnectionAborted,
+            Self::CommandIsInvalid
+            | Self::CommandIncomplete
+            | Self::SecurityDecryptError
+            | Self::InvalidSignature
+            | Self::SecurityImageChecksumError => ErrorKind::InvalidData,
+            _ => match self.category() {
+                Category::Configuration => ErrorKind::InvalidInput,
+                Category::Success
+                | Category::Informational
+                | Category::Transient
+                | Category::Retryable
+                | Category::Hardware
+                | Category::Fatal => ErrorKind::Other,
+            },
+        }
+    }
+}
+
+/// The status is kept as the inner error and can be retrieved via [`io::Error::downcast`].
+impl From<Status> for io::Error {
+    fn from(status: Status) -> Self {
+        Self::new(status.io_error_kind(), status)
+    }
+}
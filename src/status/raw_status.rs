@@ -0,0 +1,117 @@
+use core::error::Error;
+use core::fmt::{self, Display, LowerHex, UpperHex};
+
+use crate::{Space, Status};
+
+/// A raw `sl_status_t` value.
+///
+/// Unlike [`Status`], this type can represent every 32-bit status value, including codes
+/// which are not (yet) known to this library.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "le-stream",
+    derive(le_stream::FromLeStream, le_stream::ToLeStream)
+)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Ord, PartialOrd)]
+pub struct RawStatus(u32);
+
+impl RawStatus {
+    /// Creates a new `RawStatus` from a raw `sl_status_t` value.
+    #[must_use]
+    pub const fn new(value: u32) -> Self {
+        Self(value)
+    }
+
+    /// Creates a new `RawStatus` from a space and a code within that space.
+    #[must_use]
+    pub const fn from_parts(space: Space, code: u8) -> Self {
+        Self(space as u32 | code as u32)
+    }
+
+    /// Returns the raw `sl_status_t` value.
+    #[must_use]
+    pub const fn value(self) -> u32 {
+        self.0
+    }
+
+    /// Returns the known status.
+    ///
+    /// # Errors
+    ///
+    /// Returns the raw value if the status is not recognized.
+    pub fn status(self) -> Result<Status, u32> {
+        Status::try_from(self.0)
+    }
+
+    /// Returns the space the status belongs to, if it is known.
+    #[must_use]
+    pub fn space(self) -> Option<Space> {
+        Space::try_from(self.0).ok()
+    }
+
+    /// Returns the code within the status' space.
+    #[must_use]
+    pub const fn code_in_space(self) -> u8 {
+        self.0.to_le_bytes()[0]
+    }
+}
+
+impl From<u32> for RawStatus {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+impl From<RawStatus> for u32 {
+    fn from(status: RawStatus) -> Self {
+        status.0
+    }
+}
+
+impl From<Status> for RawStatus {
+    fn from(status: Status) -> Self {
+        Self(status.into())
+    }
+}
+
+impl TryFrom<RawStatus> for Status {
+    type Error = u32;
+
+    fn try_from(status: RawStatus) -> Result<Self, Self::Error> {
+        status.status()
+    }
+}
+
+impl Display for RawStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.status(), self.space()) {
+            (Ok(status), _) => Display::fmt(&status, f),
+            (Err(value), Some(space)) => write!(f, "unknown status {value:#010x} in {space}"),
+            (Err(value), None) => write!(f, "unknown status {value:#010x}"),
+        }
+    }
+}
+
+impl LowerHex for RawStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#010x}", self.0)
+    }
+}
+
+impl UpperHex for RawStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#010X}", self.0)
+    }
+}
+
+impl Error for RawStatus {}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for RawStatus {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        match self.status() {
+            Ok(status) => defmt::write!(f, "{}", status),
+            Err(value) => defmt::write!(f, "unknown status {=u32:#010x}", value),
+        }
+    }
+}
@@ -1,46 +1,26 @@
-use core::fmt::{self, Display, LowerHex, UpperHex};
+use num_derive::FromPrimitive;
+use num_traits::FromPrimitive;
 
-/// Space codes common across all platforms.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Ord, PartialOrd)]
-#[repr(u32)]
-pub enum Space {
-    /// Generic space.
-    Generic = 0x0000,
+use crate::macros::sdk_enum;
 
-    /// Wi-Fi space.
-    Wifi = 0x0B00,
+// Generated by `build.rs` from `vendor/sl_status.h`.
+include!(concat!(env!("OUT_DIR"), "/space.rs"));
 
-    /// Mask Space.
-    Mask = 0xFF00,
-}
-
-impl Space {
-    /// Return the name of the Space.
-    #[must_use]
-    pub const fn as_str(self) -> &'static str {
-        match self {
-            Self::Generic => "SL_STATUS_GENERIC_SPACE",
-            Self::Wifi => "SL_STATUS_WIFI_SPACE",
-            Self::Mask => "SL_STATUS_SPACE_MASK",
-        }
-    }
-}
+impl Space {}
 
-impl Display for Space {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.as_str())
-    }
-}
+impl TryFrom<u32> for Space {
+    type Error = u32;
 
-impl LowerHex for Space {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:#010x}", *self as u32)
-    }
-}
+    /// Extracts the space from an `sl_status_t` value.
+    ///
+    /// # Errors
+    ///
+    /// Returns the value if it exceeds 16 bits or does not belong to a known space.
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        if value > u32::from(u16::MAX) {
+            return Err(value);
+        }
 
-impl UpperHex for Space {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:#010X}", *self as u32)
+        Self::from_u32(value & Self::MASK).ok_or(value)
     }
 }
@@ -1,3 +1,8 @@
 //! Zigbee-related functionality.
 
+pub use self::ember_status::EmberStatus;
+pub use self::ezsp_status::{EzspLayer, EzspStatus};
+
+mod ember_status;
+mod ezsp_status;
 pub mod security;
@@ -0,0 +1,1156 @@
+use core::error::Error;
+
+use num_derive::FromPrimitive;
+use num_traits::FromPrimitive;
+
+use crate::Status;
+use crate::macros::sdk_enum;
+
+sdk_enum! {
+    prefix: "EMBER_",
+    noun: "ember status",
+    repr: u8,
+
+    /// Legacy status codes of `EmberZNet`, as returned by EZSP versions before 14.
+    ///
+    /// Newer stacks report [`Status`] codes instead. Converting an `EmberStatus` into a [`Status`]
+    /// allows handling both with a single error type.
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Ord, PartialOrd, FromPrimitive)]
+    pub enum EmberStatus {
+        /// The generic "no error" message.
+        Success = 0x00,
+
+        /// The generic "fatal error" message.
+        ErrFatal = 0x01,
+
+        /// An invalid value was passed as an argument to a function.
+        BadArgument = 0x02,
+
+        /// The requested information was not found.
+        NotFound = 0x03,
+
+        /// The manufacturing and stack token format in non-volatile memory is different than
+        /// what the stack expects.
+        EepromMfgStackVersionMismatch = 0x04,
+
+        /// The static memory definitions are incompatible with this stack version.
+        IncompatibleStaticMemoryDefinitions = 0x05,
+
+        /// The manufacturing token format in non-volatile memory is different than what the
+        /// stack expects.
+        EepromMfgVersionMismatch = 0x06,
+
+        /// The stack token format in non-volatile memory is different than what the stack
+        /// expects.
+        EepromStackVersionMismatch = 0x07,
+
+        /// There are no more buffers.
+        NoBuffers = 0x18,
+
+        /// Packet is dropped by packet-handoff callbacks.
+        PacketHandoffDropPacket = 0x19,
+
+        /// Specified an invalid baud rate.
+        SerialInvalidBaudRate = 0x20,
+
+        /// Specified an invalid serial port.
+        SerialInvalidPort = 0x21,
+
+        /// Tried to send too much data.
+        SerialTxOverflow = 0x22,
+
+        /// There wasn't enough space to store a received character and the character was
+        /// dropped.
+        SerialRxOverflow = 0x23,
+
+        /// Detected a UART framing error.
+        SerialRxFrameError = 0x24,
+
+        /// Detected a UART parity error.
+        SerialRxParityError = 0x25,
+
+        /// There is no received data to process.
+        SerialRxEmpty = 0x26,
+
+        /// The receive interrupt was not handled in time and a character was dropped.
+        SerialRxOverrunError = 0x27,
+
+        /// No pending data exists for a data poll.
+        MacNoData = 0x31,
+
+        /// Attempted to scan or join while the node is joined to a network.
+        MacJoinedNetwork = 0x32,
+
+        /// The scan duration must be 0 to 14 inclusive.
+        MacBadScanDuration = 0x33,
+
+        /// A scan was started with an incorrect scan type.
+        MacIncorrectScanType = 0x34,
+
+        /// The channel mask of a scan was invalid.
+        MacInvalidChannelMask = 0x35,
+
+        /// Failed to scan the current channel because the relevant MAC command could not be
+        /// transmitted.
+        MacCommandTransmitFailure = 0x36,
+
+        /// The MAC transmit queue is full.
+        MacTransmitQueueFull = 0x39,
+
+        /// MAC header FCF error on receive.
+        MacUnknownHeaderType = 0x3A,
+
+        /// MAC ACK header received.
+        MacAckHeaderType = 0x3B,
+
+        /// The MAC can't complete this task because it is scanning.
+        MacScanning = 0x3D,
+
+        /// Expected to receive an ACK following the transmission, but the MAC level ACK was
+        /// never received.
+        MacNoAckReceived = 0x40,
+
+        /// The MAC failed to transmit a message because it could not perform a radio network
+        /// switch.
+        MacRadioNetworkSwitchFailed = 0x41,
+
+        /// An indirect data message timed out before a poll requested it.
+        MacIndirectTimeout = 0x42,
+
+        /// The Simulated EEPROM is telling the application that at least one flash page needs
+        /// to be erased.
+        SimEepromErasePageGreen = 0x43,
+
+        /// The Simulated EEPROM is telling the application that the flash pages must be erased
+        /// immediately.
+        SimEepromErasePageRed = 0x44,
+
+        /// The Simulated EEPROM has run out of room to write new data and the data trying to be
+        /// set has been lost.
+        SimEepromFull = 0x45,
+
+        /// Writing to the flash failed because the target memory is already programmed.
+        ErrFlashWriteInhibited = 0x46,
+
+        /// Writing to the flash failed because the write verification has failed.
+        ErrFlashVerifyFailed = 0x47,
+
+        /// Attempt 1 to initialize the Simulated EEPROM has failed.
+        SimEepromInit1Failed = 0x48,
+
+        /// Attempt 2 to initialize the Simulated EEPROM has failed.
+        SimEepromInit2Failed = 0x49,
+
+        /// Attempt 3 to initialize the Simulated EEPROM has failed.
+        SimEepromInit3Failed = 0x4A,
+
+        /// Writing to the flash failed because programming has failed.
+        ErrFlashProgFail = 0x4B,
+
+        /// Erasing the flash failed.
+        ErrFlashEraseFail = 0x4C,
+
+        /// The Simulated EEPROM is repairing itself.
+        SimEepromRepairing = 0x4D,
+
+        /// The bootloader received an invalid message (failed attempt to go into bootloader).
+        ErrBootloaderTrapTableBad = 0x58,
+
+        /// The bootloader received an unknown trap request.
+        ErrBootloaderTrapUnknown = 0x59,
+
+        /// The bootloader cannot complete the bootload operation because either an image was
+        /// not found or the image exceeded memory bounds.
+        ErrBootloaderNoImage = 0x5A,
+
+        /// The APS layer attempted to send or deliver a message and failed.
+        DeliveryFailed = 0x66,
+
+        /// This binding index is out of range for the current binding table.
+        BindingIndexOutOfRange = 0x69,
+
+        /// This address table index is out of range for the current address table.
+        AddressTableIndexOutOfRange = 0x6A,
+
+        /// An invalid binding table index was given to a function.
+        InvalidBindingIndex = 0x6C,
+
+        /// The API call is not allowed given the current state of the stack.
+        InvalidCall = 0x70,
+
+        /// The link cost to a node is not known.
+        CostNotKnown = 0x71,
+
+        /// The maximum number of in-flight messages has been reached.
+        MaxMessageLimitReached = 0x72,
+
+        /// The message to be transmitted is too big to fit into a single over-the-air packet.
+        MessageTooLong = 0x74,
+
+        /// The application is trying to delete or overwrite a binding that is in use.
+        BindingIsActive = 0x75,
+
+        /// The application is trying to overwrite an address table entry that is in use.
+        AddressTableEntryIsActive = 0x76,
+
+        /// An attempt was made to transmit during the suspend period.
+        TransmissionSuspended = 0x77,
+
+        /// Security match.
+        Match = 0x78,
+
+        /// Drop frame.
+        DropFrame = 0x79,
+
+        /// Pass the frame on unprocessed.
+        PassUnprocessed = 0x7A,
+
+        /// Transmit the frame, then drop it.
+        TxThenDrop = 0x7B,
+
+        /// No security.
+        NoSecurity = 0x7C,
+
+        /// Conversion is complete.
+        AdcConversionDone = 0x80,
+
+        /// Conversion cannot be done because a request is being processed.
+        AdcConversionBusy = 0x81,
+
+        /// Conversion is deferred until the current request has been processed.
+        AdcConversionDeferred = 0x82,
+
+        /// No results are pending.
+        AdcNoConversionPending = 0x84,
+
+        /// Sleeping (for a duration) has been abnormally interrupted and exited prematurely.
+        SleepInterrupted = 0x85,
+
+        /// The transmit attempt failed because the radio scheduler could not find a slot to
+        /// transmit this packet in or a higher priority event interrupted it.
+        PhyTxSchedFail = 0x87,
+
+        /// The transmit hardware buffer underflowed.
+        PhyTxUnderflow = 0x88,
+
+        /// The transmit hardware did not finish transmitting a packet.
+        PhyTxIncomplete = 0x89,
+
+        /// An unsupported channel setting was specified.
+        PhyInvalidChannel = 0x8A,
+
+        /// An unsupported power setting was specified.
+        PhyInvalidPower = 0x8B,
+
+        /// The requested operation cannot be completed because the radio is currently busy,
+        /// either transmitting a packet or performing calibration.
+        PhyTxBusy = 0x8C,
+
+        /// The transmit attempt failed because all CCA attempts indicated that the channel was
+        /// busy.
+        PhyTxCcaFail = 0x8D,
+
+        /// The transmit attempt failed because the oscillator check failed.
+        PhyOscillatorCheckFailed = 0x8E,
+
+        /// The expected ACK was received after the last transmission.
+        PhyAckReceived = 0x8F,
+
+        /// The stack software has completed initialization and is ready to send and receive
+        /// packets over the air.
+        NetworkUp = 0x90,
+
+        /// The network is not operating.
+        NetworkDown = 0x91,
+
+        /// The node has not joined a network.
+        NotJoined = 0x93,
+
+        /// An attempt to join a network failed.
+        JoinFailed = 0x94,
+
+        /// The chosen security level is not supported by the stack.
+        InvalidSecurityLevel = 0x95,
+
+        /// After moving, a mobile node's attempt to re-establish contact with the network
+        /// failed.
+        MoveFailed = 0x96,
+
+        /// An attempt to join as a router failed due to a Zigbee versus Zigbee Pro
+        /// incompatibility.
+        CannotJoinAsRouter = 0x98,
+
+        /// The local node ID has changed.
+        NodeIdChanged = 0x99,
+
+        /// The local PAN ID has changed.
+        PanIdChanged = 0x9A,
+
+        /// The channel has changed.
+        ChannelChanged = 0x9B,
+
+        /// The network has been opened for joining.
+        NetworkOpened = 0x9C,
+
+        /// The network has been closed for joining.
+        NetworkClosed = 0x9D,
+
+        /// A message cannot be sent because the network is currently overloaded.
+        NetworkBusy = 0xA1,
+
+        /// The application tried to send a message using an endpoint that it has not defined.
+        InvalidEndpoint = 0xA3,
+
+        /// The application tried to use a binding that has been remotely modified and the
+        /// change has not yet been reported to the application.
+        BindingHasChanged = 0xA4,
+
+        /// An attempt to generate random bytes failed because of insufficient random data from
+        /// the radio.
+        InsufficientRandomData = 0xA5,
+
+        /// There was an error in trying to encrypt at the APS level.
+        ApsEncryptionError = 0xA6,
+
+        /// There was an attempt to form or join a network with security without setting the
+        /// initial security state first.
+        SecurityStateNotSet = 0xA8,
+
+        /// A route error command frame was received indicating that a source routed message
+        /// from this node failed en route.
+        SourceRouteFailure = 0xA9,
+
+        /// A route error command frame was received indicating that a message sent to this node
+        /// along a many-to-one route failed en route.
+        ManyToOneRouteFailure = 0xAA,
+
+        /// The node could not join because no beacons were received.
+        NoBeacons = 0xAB,
+
+        /// An attempt was made to join a secured network using a pre-configured key, but the
+        /// Trust Center sent back a network key in the clear when an encrypted network key was
+        /// required.
+        ReceivedKeyInTheClear = 0xAC,
+
+        /// An attempt was made to join a secured network, but the device did not receive a
+        /// network key.
+        NoNetworkKeyReceived = 0xAD,
+
+        /// After a device joined a secured network, a link key was requested but no response
+        /// was ever received.
+        NoLinkKeyReceived = 0xAE,
+
+        /// An attempt was made to join a secured network without a pre-configured key, but the
+        /// Trust Center sent encrypted data using a pre-configured key.
+        PreconfiguredKeyRequired = 0xAF,
+
+        /// The version of the stack trying to run does not match the chip it is running on.
+        StackAndHardwareMismatch = 0xB0,
+
+        /// An index was passed into the function that was larger than the valid range.
+        IndexOutOfRange = 0xB1,
+
+        /// The passed key data is not valid.
+        KeyInvalid = 0xB2,
+
+        /// The specified address is not valid for the key table.
+        KeyTableInvalidAddress = 0xB3,
+
+        /// There are no empty entries left in the table.
+        TableFull = 0xB4,
+
+        /// The requested function cannot be executed because the library that contains the
+        /// necessary functionality is not present.
+        LibraryNotPresent = 0xB5,
+
+        /// The requested table entry has been erased and contains no valid data.
+        TableEntryErased = 0xB6,
+
+        /// The requested security configuration is not valid.
+        SecurityConfigurationInvalid = 0xB7,
+
+        /// The network key could not be switched because it was sent too recently.
+        TooSoonForSwitchKey = 0xB8,
+
+        /// The received signature corresponding to the message that was passed to the CBKE
+        /// library failed verification and is not valid.
+        SignatureVerifyFailure = 0xB9,
+
+        /// The operation cannot be performed because another operation is in progress.
+        OperationInProgress = 0xBA,
+
+        /// The message could not be sent because the link key corresponding to this destination
+        /// has not been authorized.
+        KeyNotAuthorized = 0xBB,
+
+        /// The EUI64 of the Trust Center has changed since the node last joined the network.
+        TrustCenterEuiHasChanged = 0xBC,
+
+        /// The security data provided was not valid, or an integrity check failed.
+        SecurityDataInvalid = 0xBD,
+
+        /// The node is currently performing an IEEE address discovery and cannot perform the
+        /// requested operation.
+        IeeeAddressDiscoveryInProgress = 0xBE,
+
+        /// Application-defined error 0.
+        ApplicationError0 = 0xF0,
+
+        /// Application-defined error 1.
+        ApplicationError1 = 0xF1,
+
+        /// Application-defined error 2.
+        ApplicationError2 = 0xF2,
+
+        /// Application-defined error 3.
+        ApplicationError3 = 0xF3,
+
+        /// Application-defined error 4.
+        ApplicationError4 = 0xF4,
+
+        /// Application-defined error 5.
+        ApplicationError5 = 0xF5,
+
+        /// Application-defined error 6.
+        ApplicationError6 = 0xF6,
+
+        /// Application-defined error 7.
+        ApplicationError7 = 0xF7,
+
+        /// Application-defined error 8.
+        ApplicationError8 = 0xF8,
+
+        /// Application-defined error 9.
+        ApplicationError9 = 0xF9,
+
+        /// Application-defined error 10.
+        ApplicationError10 = 0xFA,
+
+        /// Application-defined error 11.
+        ApplicationError11 = 0xFB,
+
+        /// Application-defined error 12.
+        ApplicationError12 = 0xFC,
+
+        /// Application-defined error 13.
+        ApplicationError13 = 0xFD,
+
+        /// Application-defined error 14.
+        ApplicationError14 = 0xFE,
+
+        /// Application-defined error 15.
+        ApplicationError15 = 0xFF,
+    }
+}
+
+impl EmberStatus {
+    /// Return the name of the status.
+    #[expect(clippy::too_many_lines)]
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Success => "EMBER_SUCCESS",
+            Self::ErrFatal => "EMBER_ERR_FATAL",
+            Self::BadArgument => "EMBER_BAD_ARGUMENT",
+            Self::NotFound => "EMBER_NOT_FOUND",
+            Self::EepromMfgStackVersionMismatch => "EMBER_EEPROM_MFG_STACK_VERSION_MISMATCH",
+            Self::IncompatibleStaticMemoryDefinitions => {
+                "EMBER_INCOMPATIBLE_STATIC_MEMORY_DEFINITIONS"
+            }
+            Self::EepromMfgVersionMismatch => "EMBER_EEPROM_MFG_VERSION_MISMATCH",
+            Self::EepromStackVersionMismatch => "EMBER_EEPROM_STACK_VERSION_MISMATCH",
+            Self::NoBuffers => "EMBER_NO_BUFFERS",
+            Self::PacketHandoffDropPacket => "EMBER_PACKET_HANDOFF_DROP_PACKET",
+            Self::SerialInvalidBaudRate => "EMBER_SERIAL_INVALID_BAUD_RATE",
+            Self::SerialInvalidPort => "EMBER_SERIAL_INVALID_PORT",
+            Self::SerialTxOverflow => "EMBER_SERIAL_TX_OVERFLOW",
+            Self::SerialRxOverflow => "EMBER_SERIAL_RX_OVERFLOW",
+            Self::SerialRxFrameError => "EMBER_SERIAL_RX_FRAME_ERROR",
+            Self::SerialRxParityError => "EMBER_SERIAL_RX_PARITY_ERROR",
+            Self::SerialRxEmpty => "EMBER_SERIAL_RX_EMPTY",
+            Self::SerialRxOverrunError => "EMBER_SERIAL_RX_OVERRUN_ERROR",
+            Self::MacNoData => "EMBER_MAC_NO_DATA",
+            Self::MacJoinedNetwork => "EMBER_MAC_JOINED_NETWORK",
+            Self::MacBadScanDuration => "EMBER_MAC_BAD_SCAN_DURATION",
+            Self::MacIncorrectScanType => "EMBER_MAC_INCORRECT_SCAN_TYPE",
+            Self::MacInvalidChannelMask => "EMBER_MAC_INVALID_CHANNEL_MASK",
+            Self::MacCommandTransmitFailure => "EMBER_MAC_COMMAND_TRANSMIT_FAILURE",
+            Self::MacTransmitQueueFull => "EMBER_MAC_TRANSMIT_QUEUE_FULL",
+            Self::MacUnknownHeaderType => "EMBER_MAC_UNKNOWN_HEADER_TYPE",
+            Self::MacAckHeaderType => "EMBER_MAC_ACK_HEADER_TYPE",
+            Self::MacScanning => "EMBER_MAC_SCANNING",
+            Self::MacNoAckReceived => "EMBER_MAC_NO_ACK_RECEIVED",
+            Self::MacRadioNetworkSwitchFailed => "EMBER_MAC_RADIO_NETWORK_SWITCH_FAILED",
+            Self::MacIndirectTimeout => "EMBER_MAC_INDIRECT_TIMEOUT",
+            Self::SimEepromErasePageGreen => "EMBER_SIM_EEPROM_ERASE_PAGE_GREEN",
+            Self::SimEepromErasePageRed => "EMBER_SIM_EEPROM_ERASE_PAGE_RED",
+            Self::SimEepromFull => "EMBER_SIM_EEPROM_FULL",
+            Self::ErrFlashWriteInhibited => "EMBER_ERR_FLASH_WRITE_INHIBITED",
+            Self::ErrFlashVerifyFailed => "EMBER_ERR_FLASH_VERIFY_FAILED",
+            Self::SimEepromInit1Failed => "EMBER_SIM_EEPROM_INIT_1_FAILED",
+            Self::SimEepromInit2Failed => "EMBER_SIM_EEPROM_INIT_2_FAILED",
+            Self::SimEepromInit3Failed => "EMBER_SIM_EEPROM_INIT_3_FAILED",
+            Self::ErrFlashProgFail => "EMBER_ERR_FLASH_PROG_FAIL",
+            Self::ErrFlashEraseFail => "EMBER_ERR_FLASH_ERASE_FAIL",
+            Self::SimEepromRepairing => "EMBER_SIM_EEPROM_REPAIRING",
+            Self::ErrBootloaderTrapTableBad => "EMBER_ERR_BOOTLOADER_TRAP_TABLE_BAD",
+            Self::ErrBootloaderTrapUnknown => "EMBER_ERR_BOOTLOADER_TRAP_UNKNOWN",
+            Self::ErrBootloaderNoImage => "EMBER_ERR_BOOTLOADER_NO_IMAGE",
+            Self::DeliveryFailed => "EMBER_DELIVERY_FAILED",
+            Self::BindingIndexOutOfRange => "EMBER_BINDING_INDEX_OUT_OF_RANGE",
+            Self::AddressTableIndexOutOfRange => "EMBER_ADDRESS_TABLE_INDEX_OUT_OF_RANGE",
+            Self::InvalidBindingIndex => "EMBER_INVALID_BINDING_INDEX",
+            Self::InvalidCall => "EMBER_INVALID_CALL",
+            Self::CostNotKnown => "EMBER_COST_NOT_KNOWN",
+            Self::MaxMessageLimitReached => "EMBER_MAX_MESSAGE_LIMIT_REACHED",
+            Self::MessageTooLong => "EMBER_MESSAGE_TOO_LONG",
+            Self::BindingIsActive => "EMBER_BINDING_IS_ACTIVE",
+            Self::AddressTableEntryIsActive => "EMBER_ADDRESS_TABLE_ENTRY_IS_ACTIVE",
+            Self::TransmissionSuspended => "EMBER_TRANSMISSION_SUSPENDED",
+            Self::Match => "EMBER_MATCH",
+            Self::DropFrame => "EMBER_DROP_FRAME",
+            Self::PassUnprocessed => "EMBER_PASS_UNPROCESSED",
+            Self::TxThenDrop => "EMBER_TX_THEN_DROP",
+            Self::NoSecurity => "EMBER_NO_SECURITY",
+            Self::AdcConversionDone => "EMBER_ADC_CONVERSION_DONE",
+            Self::AdcConversionBusy => "EMBER_ADC_CONVERSION_BUSY",
+            Self::AdcConversionDeferred => "EMBER_ADC_CONVERSION_DEFERRED",
+            Self::AdcNoConversionPending => "EMBER_ADC_NO_CONVERSION_PENDING",
+            Self::SleepInterrupted => "EMBER_SLEEP_INTERRUPTED",
+            Self::PhyTxSchedFail => "EMBER_PHY_TX_SCHED_FAIL",
+            Self::PhyTxUnderflow => "EMBER_PHY_TX_UNDERFLOW",
+            Self::PhyTxIncomplete => "EMBER_PHY_TX_INCOMPLETE",
+            Self::PhyInvalidChannel => "EMBER_PHY_INVALID_CHANNEL",
+            Self::PhyInvalidPower => "EMBER_PHY_INVALID_POWER",
+            Self::PhyTxBusy => "EMBER_PHY_TX_BUSY",
+            Self::PhyTxCcaFail => "EMBER_PHY_TX_CCA_FAIL",
+            Self::PhyOscillatorCheckFailed => "EMBER_PHY_OSCILLATOR_CHECK_FAILED",
+            Self::PhyAckReceived => "EMBER_PHY_ACK_RECEIVED",
+            Self::NetworkUp => "EMBER_NETWORK_UP",
+            Self::NetworkDown => "EMBER_NETWORK_DOWN",
+            Self::NotJoined => "EMBER_NOT_JOINED",
+            Self::JoinFailed => "EMBER_JOIN_FAILED",
+            Self::InvalidSecurityLevel => "EMBER_INVALID_SECURITY_LEVEL",
+            Self::MoveFailed => "EMBER_MOVE_FAILED",
+            Self::CannotJoinAsRouter => "EMBER_CANNOT_JOIN_AS_ROUTER",
+            Self::NodeIdChanged => "EMBER_NODE_ID_CHANGED",
+            Self::PanIdChanged => "EMBER_PAN_ID_CHANGED",
+            Self::ChannelChanged => "EMBER_CHANNEL_CHANGED",
+            Self::NetworkOpened => "EMBER_NETWORK_OPENED",
+            Self::NetworkClosed => "EMBER_NETWORK_CLOSED",
+            Self::NetworkBusy => "EMBER_NETWORK_BUSY",
+            Self::InvalidEndpoint => "EMBER_INVALID_ENDPOINT",
+            Self::BindingHasChanged => "EMBER_BINDING_HAS_CHANGED",
+            Self::InsufficientRandomData => "EMBER_INSUFFICIENT_RANDOM_DATA",
+            Self::ApsEncryptionError => "EMBER_APS_ENCRYPTION_ERROR",
+            Self::SecurityStateNotSet => "EMBER_SECURITY_STATE_NOT_SET",
+            Self::SourceRouteFailure => "EMBER_SOURCE_ROUTE_FAILURE",
+            Self::ManyToOneRouteFailure => "EMBER_MANY_TO_ONE_ROUTE_FAILURE",
+            Self::NoBeacons => "EMBER_NO_BEACONS",
+            Self::ReceivedKeyInTheClear => "EMBER_RECEIVED_KEY_IN_THE_CLEAR",
+            Self::NoNetworkKeyReceived => "EMBER_NO_NETWORK_KEY_RECEIVED",
+            Self::NoLinkKeyReceived => "EMBER_NO_LINK_KEY_RECEIVED",
+            Self::PreconfiguredKeyRequired => "EMBER_PRECONFIGURED_KEY_REQUIRED",
+            Self::StackAndHardwareMismatch => "EMBER_STACK_AND_HARDWARE_MISMATCH",
+            Self::IndexOutOfRange => "EMBER_INDEX_OUT_OF_RANGE",
+            Self::KeyInvalid => "EMBER_KEY_INVALID",
+            Self::KeyTableInvalidAddress => "EMBER_KEY_TABLE_INVALID_ADDRESS",
+            Self::TableFull => "EMBER_TABLE_FULL",
+            Self::LibraryNotPresent => "EMBER_LIBRARY_NOT_PRESENT",
+            Self::TableEntryErased => "EMBER_TABLE_ENTRY_ERASED",
+            Self::SecurityConfigurationInvalid => "EMBER_SECURITY_CONFIGURATION_INVALID",
+            Self::TooSoonForSwitchKey => "EMBER_TOO_SOON_FOR_SWITCH_KEY",
+            Self::SignatureVerifyFailure => "EMBER_SIGNATURE_VERIFY_FAILURE",
+            Self::OperationInProgress => "EMBER_OPERATION_IN_PROGRESS",
+            Self::KeyNotAuthorized => "EMBER_KEY_NOT_AUTHORIZED",
+            Self::TrustCenterEuiHasChanged => "EMBER_TRUST_CENTER_EUI_HAS_CHANGED",
+            Self::SecurityDataInvalid => "EMBER_SECURITY_DATA_INVALID",
+            Self::IeeeAddressDiscoveryInProgress => "EMBER_IEEE_ADDRESS_DISCOVERY_IN_PROGRESS",
+            Self::ApplicationError0 => "EMBER_APPLICATION_ERROR_0",
+            Self::ApplicationError1 => "EMBER_APPLICATION_ERROR_1",
+            Self::ApplicationError2 => "EMBER_APPLICATION_ERROR_2",
+            Self::ApplicationError3 => "EMBER_APPLICATION_ERROR_3",
+            Self::ApplicationError4 => "EMBER_APPLICATION_ERROR_4",
+            Self::ApplicationError5 => "EMBER_APPLICATION_ERROR_5",
+            Self::ApplicationError6 => "EMBER_APPLICATION_ERROR_6",
+            Self::ApplicationError7 => "EMBER_APPLICATION_ERROR_7",
+            Self::ApplicationError8 => "EMBER_APPLICATION_ERROR_8",
+            Self::ApplicationError9 => "EMBER_APPLICATION_ERROR_9",
+            Self::ApplicationError10 => "EMBER_APPLICATION_ERROR_10",
+            Self::ApplicationError11 => "EMBER_APPLICATION_ERROR_11",
+            Self::ApplicationError12 => "EMBER_APPLICATION_ERROR_12",
+            Self::ApplicationError13 => "EMBER_APPLICATION_ERROR_13",
+            Self::ApplicationError14 => "EMBER_APPLICATION_ERROR_14",
+            Self::ApplicationError15 => "EMBER_APPLICATION_ERROR_15",
+        }
+    }
+
+    /// Return a human-readable description of the status.
+    #[expect(clippy::too_many_lines)]
+    #[must_use]
+    pub const fn description(self) -> &'static str {
+        match self {
+            Self::Success => "The generic \"no error\" message.",
+            Self::ErrFatal => "The generic \"fatal error\" message.",
+            Self::BadArgument => "An invalid value was passed as an argument to a function.",
+            Self::NotFound => "The requested information was not found.",
+            Self::EepromMfgStackVersionMismatch => {
+                "The manufacturing and stack token format in non-volatile memory is different than what the stack expects."
+            }
+            Self::IncompatibleStaticMemoryDefinitions => {
+                "The static memory definitions are incompatible with this stack version."
+            }
+            Self::EepromMfgVersionMismatch => {
+                "The manufacturing token format in non-volatile memory is different than what the stack expects."
+            }
+            Self::EepromStackVersionMismatch => {
+                "The stack token format in non-volatile memory is different than what the stack expects."
+            }
+            Self::NoBuffers => "There are no more buffers.",
+            Self::PacketHandoffDropPacket => "Packet is dropped by packet-handoff callbacks.",
+            Self::SerialInvalidBaudRate => "Specified an invalid baud rate.",
+            Self::SerialInvalidPort => "Specified an invalid serial port.",
+            Self::SerialTxOverflow => "Tried to send too much data.",
+            Self::SerialRxOverflow => {
+                "There wasn't enough space to store a received character and the character was dropped."
+            }
+            Self::SerialRxFrameError => "Detected a UART framing error.",
+            Self::SerialRxParityError => "Detected a UART parity error.",
+            Self::SerialRxEmpty => "There is no received data to process.",
+            Self::SerialRxOverrunError => {
+                "The receive interrupt was not handled in time and a character was dropped."
+            }
+            Self::MacNoData => "No pending data exists for a data poll.",
+            Self::MacJoinedNetwork => {
+                "Attempted to scan or join while the node is joined to a network."
+            }
+            Self::MacBadScanDuration => "The scan duration must be 0 to 14 inclusive.",
+            Self::MacIncorrectScanType => "A scan was started with an incorrect scan type.",
+            Self::MacInvalidChannelMask => "The channel mask of a scan was invalid.",
+            Self::MacCommandTransmitFailure => {
+                "Failed to scan the current channel because the relevant MAC command could not be transmitted."
+            }
+            Self::MacTransmitQueueFull => "The MAC transmit queue is full.",
+            Self::MacUnknownHeaderType => "MAC header FCF error on receive.",
+            Self::MacAckHeaderType => "MAC ACK header received.",
+            Self::MacScanning => "The MAC can't complete this task because it is scanning.",
+            Self::MacNoAckReceived => {
+                "Expected to receive an ACK following the transmission, but the MAC level ACK was never received."
+            }
+            Self::MacRadioNetworkSwitchFailed => {
+                "The MAC failed to transmit a message because it could not perform a radio network switch."
+            }
+            Self::MacIndirectTimeout => {
+                "An indirect data message timed out before a poll requested it."
+            }
+            Self::SimEepromErasePageGreen => {
+                "The Simulated EEPROM is telling the application that at least one flash page needs to be erased."
+            }
+            Self::SimEepromErasePageRed => {
+                "The Simulated EEPROM is telling the application that the flash pages must be erased immediately."
+            }
+            Self::SimEepromFull => {
+                "The Simulated EEPROM has run out of room to write new data and the data trying to be set has been lost."
+            }
+            Self::ErrFlashWriteInhibited => {
+                "Writing to the flash failed because the target memory is already programmed."
+            }
+            Self::ErrFlashVerifyFailed => {
+                "Writing to the flash failed because the write verification has failed."
+            }
+            Self::SimEepromInit1Failed => {
+                "Attempt 1 to initialize the Simulated EEPROM has failed."
+            }
+            Self::SimEepromInit2Failed => {
+                "Attempt 2 to initialize the Simulated EEPROM has failed."
+            }
+            Self::SimEepromInit3Failed => {
+                "Attempt 3 to initialize the Simulated EEPROM has failed."
+            }
+            Self::ErrFlashProgFail => "Writing to the flash failed because programming has failed.",
+            Self::ErrFlashEraseFail => "Erasing the flash failed.",
+            Self::SimEepromRepairing => "The Simulated EEPROM is repairing itself.",
+            Self::ErrBootloaderTrapTableBad => {
+                "The bootloader received an invalid message (failed attempt to go into bootloader)."
+            }
+            Self::ErrBootloaderTrapUnknown => "The bootloader received an unknown trap request.",
+            Self::ErrBootloaderNoImage => {
+                "The bootloader cannot complete the bootload operation because either an image was not found or the image exceeded memory bounds."
+            }
+            Self::DeliveryFailed => {
+                "The APS layer attempted to send or deliver a message and failed."
+            }
+            Self::BindingIndexOutOfRange => {
+                "This binding index is out of range for the current binding table."
+            }
+            Self::AddressTableIndexOutOfRange => {
+                "This address table index is out of range for the current address table."
+            }
+            Self::InvalidBindingIndex => "An invalid binding table index was given to a function.",
+            Self::InvalidCall => {
+                "The API call is not allowed given the current state of the stack."
+            }
+            Self::CostNotKnown => "The link cost to a node is not known.",
+            Self::MaxMessageLimitReached => {
+                "The maximum number of in-flight messages has been reached."
+            }
+            Self::MessageTooLong => {
+                "The message to be transmitted is too big to fit into a single over-the-air packet."
+            }
+            Self::BindingIsActive => {
+                "The application is trying to delete or overwrite a binding that is in use."
+            }
+            Self::AddressTableEntryIsActive => {
+                "The application is trying to overwrite an address table entry that is in use."
+            }
+            Self::TransmissionSuspended => {
+                "An attempt was made to transmit during the suspend period."
+            }
+            Self::Match => "Security match.",
+            Self::DropFrame => "Drop frame.",
+            Self::PassUnprocessed => "Pass the frame on unprocessed.",
+            Self::TxThenDrop => "Transmit the frame, then drop it.",
+            Self::NoSecurity => "No security.",
+            Self::AdcConversionDone => "Conversion is complete.",
+            Self::AdcConversionBusy => {
+                "Conversion cannot be done because a request is being processed."
+            }
+            Self::AdcConversionDeferred => {
+                "Conversion is deferred until the current request has been processed."
+            }
+            Self::AdcNoConversionPending => "No results are pending.",
+            Self::SleepInterrupted => {
+                "Sleeping (for a duration) has been abnormally interrupted and exited prematurely."
+            }
+            Self::PhyTxSchedFail => {
+                "The transmit attempt failed because the radio scheduler could not find a slot to transmit this packet in or a higher priority event interrupted it."
+            }
+            Self::PhyTxUnderflow => "The transmit hardware buffer underflowed.",
+            Self::PhyTxIncomplete => "The transmit hardware did not finish transmitting a packet.",
+            Self::PhyInvalidChannel => "An unsupported channel setting was specified.",
+            Self::PhyInvalidPower => "An unsupported power setting was specified.",
+            Self::PhyTxBusy => {
+                "The requested operation cannot be completed because the radio is currently busy, either transmitting a packet or performing calibration."
+            }
+            Self::PhyTxCcaFail => {
+                "The transmit attempt failed because all CCA attempts indicated that the channel was busy."
+            }
+            Self::PhyOscillatorCheckFailed => {
+                "The transmit attempt failed because the oscillator check failed."
+            }
+            Self::PhyAckReceived => "The expected ACK was received after the last transmission.",
+            Self::NetworkUp => {
+                "The stack software has completed initialization and is ready to send and receive packets over the air."
+            }
+            Self::NetworkDown => "The network is not operating.",
+            Self::NotJoined => "The node has not joined a network.",
+            Self::JoinFailed => "An attempt to join a network failed.",
+            Self::InvalidSecurityLevel => {
+                "The chosen security level is not supported by the stack."
+            }
+            Self::MoveFailed => {
+                "After moving, a mobile node's attempt to re-establish contact with the network failed."
+            }
+            Self::CannotJoinAsRouter => {
+                "An attempt to join as a router failed due to a Zigbee versus Zigbee Pro incompatibility."
+            }
+            Self::NodeIdChanged => "The local node ID has changed.",
+            Self::PanIdChanged => "The local PAN ID has changed.",
+            Self::ChannelChanged => "The channel has changed.",
+            Self::NetworkOpened => "The network has been opened for joining.",
+            Self::NetworkClosed => "The network has been closed for joining.",
+            Self::NetworkBusy => {
+                "A message cannot be sent because the network is currently overloaded."
+            }
+            Self::InvalidEndpoint => {
+                "The application tried to send a message using an endpoint that it has not defined."
+            }
+            Self::BindingHasChanged => {
+                "The application tried to use a binding that has been remotely modified and the change has not yet been reported to the application."
+            }
+            Self::InsufficientRandomData => {
+                "An attempt to generate random bytes failed because of insufficient random data from the radio."
+            }
+            Self::ApsEncryptionError => "There was an error in trying to encrypt at the APS level.",
+            Self::SecurityStateNotSet => {
+                "There was an attempt to form or join a network with security without setting the initial security state first."
+            }
+            Self::SourceRouteFailure => {
+                "A route error command frame was received indicating that a source routed message from this node failed en route."
+            }
+            Self::ManyToOneRouteFailure => {
+                "A route error command frame was received indicating that a message sent to this node along a many-to-one route failed en route."
+            }
+            Self::NoBeacons => "The node could not join because no beacons were received.",
+            Self::ReceivedKeyInTheClear => {
+                "An attempt was made to join a secured network using a pre-configured key, but the Trust Center sent back a network key in the clear when an encrypted network key was required."
+            }
+            Self::NoNetworkKeyReceived => {
+                "An attempt was made to join a secured network, but the device did not receive a network key."
+            }
+            Self::NoLinkKeyReceived => {
+                "After a device joined a secured network, a link key was requested but no response was ever received."
+            }
+            Self::PreconfiguredKeyRequired => {
+                "An attempt was made to join a secured network without a pre-configured key, but the Trust Center sent encrypted data using a pre-configured key."
+            }
+            Self::StackAndHardwareMismatch => {
+                "The version of the stack trying to run does not match the chip it is running on."
+            }
+            Self::IndexOutOfRange => {
+                "An index was passed into the function that was larger than the valid range."
+            }
+            Self::KeyInvalid => "The passed key data is not valid.",
+            Self::KeyTableInvalidAddress => "The specified address is not valid for the key table.",
+            Self::TableFull => "There are no empty entries left in the table.",
+            Self::LibraryNotPresent => {
+                "The requested function cannot be executed because the library that contains the necessary functionality is not present."
+            }
+            Self::TableEntryErased => {
+                "The requested table entry has been erased and contains no valid data."
+            }
+            Self::SecurityConfigurationInvalid => {
+                "The requested security configuration is not valid."
+            }
+            Self::TooSoonForSwitchKey => {
+                "The network key could not be switched because it was sent too recently."
+            }
+            Self::SignatureVerifyFailure => {
+                "The received signature corresponding to the message that was passed to the CBKE library failed verification and is not valid."
+            }
+            Self::OperationInProgress => {
+                "The operation cannot be performed because another operation is in progress."
+            }
+            Self::KeyNotAuthorized => {
+                "The message could not be sent because the link key corresponding to this destination has not been authorized."
+            }
+            Self::TrustCenterEuiHasChanged => {
+                "The EUI64 of the Trust Center has changed since the node last joined the network."
+            }
+            Self::SecurityDataInvalid => {
+                "The security data provided was not valid, or an integrity check failed."
+            }
+            Self::IeeeAddressDiscoveryInProgress => {
+                "The node is currently performing an IEEE address discovery and cannot perform the requested operation."
+            }
+            Self::ApplicationError0 => "Application-defined error 0.",
+            Self::ApplicationError1 => "Application-defined error 1.",
+            Self::ApplicationError2 => "Application-defined error 2.",
+            Self::ApplicationError3 => "Application-defined error 3.",
+            Self::ApplicationError4 => "Application-defined error 4.",
+            Self::ApplicationError5 => "Application-defined error 5.",
+            Self::ApplicationError6 => "Application-defined error 6.",
+            Self::ApplicationError7 => "Application-defined error 7.",
+            Self::ApplicationError8 => "Application-defined error 8.",
+            Self::ApplicationError9 => "Application-defined error 9.",
+            Self::ApplicationError10 => "Application-defined error 10.",
+            Self::ApplicationError11 => "Application-defined error 11.",
+            Self::ApplicationError12 => "Application-defined error 12.",
+            Self::ApplicationError13 => "Application-defined error 13.",
+            Self::ApplicationError14 => "Application-defined error 14.",
+            Self::ApplicationError15 => "Application-defined error 15.",
+        }
+    }
+}
+
+impl From<EmberStatus> for u8 {
+    fn from(status: EmberStatus) -> Self {
+        status as Self
+    }
+}
+
+impl TryFrom<u8> for EmberStatus {
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Self::from_u8(value).ok_or(value)
+    }
+}
+
+/// The mapping follows the table of `EmberStatus` codes and their `sl_status_t` replacements in the
+/// `EmberZNet` 8 migration guide of Silicon Labs.
+/// Codes without a counterpart in `sl_status_t` are mapped to [`Status::Fail`].
+impl From<EmberStatus> for Status {
+    #[expect(clippy::too_many_lines)]
+    fn from(status: EmberStatus) -> Self {
+        match status {
+            EmberStatus::Success | EmberStatus::AdcConversionDone => Self::Ok,
+            EmberStatus::ErrFatal
+            | EmberStatus::SimEepromErasePageGreen
+            | EmberStatus::SimEepromErasePageRed
+            | EmberStatus::ErrBootloaderTrapTableBad
+            | EmberStatus::ErrBootloaderTrapUnknown
+            | EmberStatus::Match
+            | EmberStatus::DropFrame
+            | EmberStatus::PassUnprocessed
+            | EmberStatus::TxThenDrop
+            | EmberStatus::NoSecurity
+            | EmberStatus::PhyOscillatorCheckFailed
+            | EmberStatus::JoinFailed
+            | EmberStatus::CannotJoinAsRouter
+            | EmberStatus::ApplicationError0
+            | EmberStatus::ApplicationError1
+            | EmberStatus::ApplicationError2
+            | EmberStatus::ApplicationError3
+            | EmberStatus::ApplicationError4
+            | EmberStatus::ApplicationError5
+            | EmberStatus::ApplicationError6
+            | EmberStatus::ApplicationError7
+            | EmberStatus::ApplicationError8
+            | EmberStatus::ApplicationError9
+            | EmberStatus::ApplicationError10
+            | EmberStatus::ApplicationError11
+            | EmberStatus::ApplicationError12
+            | EmberStatus::ApplicationError13
+            | EmberStatus::ApplicationError14
+            | EmberStatus::ApplicationError15 => Self::Fail,
+            EmberStatus::BadArgument
+            | EmberStatus::SerialInvalidBaudRate
+            | EmberStatus::SerialInvalidPort
+            | EmberStatus::InvalidEndpoint
+            | EmberStatus::KeyTableInvalidAddress => Self::InvalidParameter,
+            EmberStatus::NotFound
+            | EmberStatus::ErrBootloaderNoImage
+            | EmberStatus::CostNotKnown
+            | EmberStatus::TableEntryErased => Self::NotFound,
+            EmberStatus::EepromMfgStackVersionMismatch
+            | EmberStatus::EepromStackVersionMismatch => Self::EepromStackVersionMismatch,
+            EmberStatus::IncompatibleStaticMemoryDefinitions
+            | EmberStatus::SecurityConfigurationInvalid => Self::InvalidConfiguration,
+            EmberStatus::EepromMfgVersionMismatch => Self::EepromMfgVersionMismatch,
+            EmberStatus::NoBuffers => Self::AllocationFailed,
+            EmberStatus::PacketHandoffDropPacket => Self::ZigbeePacketHandoffDropped,
+            EmberStatus::SerialTxOverflow => Self::WouldOverflow,
+            EmberStatus::SerialRxOverflow => Self::HasOverflowed,
+            EmberStatus::SerialRxFrameError
+            | EmberStatus::SerialRxParityError
+            | EmberStatus::SerialRxOverrunError => Self::Receive,
+            EmberStatus::SerialRxEmpty => Self::StatusEmpty,
+            EmberStatus::MacNoData => Self::MacNoData,
+            EmberStatus::MacJoinedNetwork | EmberStatus::NetworkUp => Self::NetworkUp,
+            EmberStatus::MacBadScanDuration => Self::BadScanDuration,
+            EmberStatus::MacIncorrectScanType => Self::MacIncorrectScanType,
+            EmberStatus::MacInvalidChannelMask => Self::InvalidChannelMask,
+            EmberStatus::MacCommandTransmitFailure => Self::MacCommandTransmitFailure,
+            EmberStatus::MacTransmitQueueFull => Self::MacTransmitQueueFull,
+            EmberStatus::MacUnknownHeaderType => Self::MacUnknownHeaderType,
+            EmberStatus::MacAckHeaderType => Self::MacAckHeaderType,
+            EmberStatus::MacScanning => Self::MacScanning,
+            EmberStatus::MacNoAckReceived => Self::MacNoAckReceived,
+            EmberStatus::MacRadioNetworkSwitchFailed => Self::Transmit,
+            EmberStatus::MacIndirectTimeout => Self::MacIndirectTimeout,
+            EmberStatus::SimEepromFull | EmberStatus::TableFull => Self::StatusFull,
+            EmberStatus::ErrFlashWriteInhibited => Self::FlashWriteInhibited,
+            EmberStatus::ErrFlashVerifyFailed => Self::FlashVerifyFailed,
+            EmberStatus::SimEepromInit1Failed
+            | EmberStatus::SimEepromInit2Failed
+            | EmberStatus::SimEepromInit3Failed => Self::Initialization,
+            EmberStatus::ErrFlashProgFail => Self::FlashProgramFailed,
+            EmberStatus::ErrFlashEraseFail => Self::FlashEraseFailed,
+            EmberStatus::SimEepromRepairing
+            | EmberStatus::AdcConversionDeferred
+            | EmberStatus::OperationInProgress => Self::InProgress,
+            EmberStatus::DeliveryFailed => Self::ZigbeeDeliveryFailed,
+            EmberStatus::BindingIndexOutOfRange
+            | EmberStatus::AddressTableIndexOutOfRange
+            | EmberStatus::InvalidBindingIndex
+            | EmberStatus::IndexOutOfRange => Self::InvalidIndex,
+            EmberStatus::InvalidCall => Self::InvalidState,
+            EmberStatus::MaxMessageLimitReached => Self::ZigbeeMaxMessageLimitReached,
+            EmberStatus::MessageTooLong => Self::MessageTooLong,
+            EmberStatus::BindingIsActive => Self::ZigbeeBindingIsActive,
+            EmberStatus::AddressTableEntryIsActive => Self::ZigbeeAddressTableEntryIsActive,
+            EmberStatus::TransmissionSuspended => Self::TransmitBlocked,
+            EmberStatus::AdcConversionBusy | EmberStatus::NetworkBusy => Self::Busy,
+            EmberStatus::AdcNoConversionPending => Self::NoneWaiting,
+            EmberStatus::SleepInterrupted => Self::Abort,
+            EmberStatus::PhyTxSchedFail => Self::TransmitSchedulerFail,
+            EmberStatus::PhyTxUnderflow => Self::TransmitUnderflow,
+            EmberStatus::PhyTxIncomplete => Self::TransmitIncomplete,
+            EmberStatus::PhyInvalidChannel => Self::TransmitInvalidChannel,
+            EmberStatus::PhyInvalidPower => Self::TransmitInvalidPower,
+            EmberStatus::PhyTxBusy => Self::TransmitBusy,
+            EmberStatus::PhyTxCcaFail => Self::CcaFailure,
+            EmberStatus::PhyAckReceived => Self::TransmitAckReceived,
+            EmberStatus::NetworkDown => Self::NetworkDown,
+            EmberStatus::NotJoined => Self::NotJoined,
+            EmberStatus::InvalidSecurityLevel => Self::ZigbeeInvalidSecurityLevel,
+            EmberStatus::MoveFailed => Self::ZigbeeMoveFailed,
+            EmberStatus::NodeIdChanged => Self::ZigbeeNodeIdChanged,
+            EmberStatus::PanIdChanged => Self::ZigbeePanIdChanged,
+            EmberStatus::ChannelChanged => Self::ZigbeeChannelChanged,
+            EmberStatus::NetworkOpened => Self::ZigbeeNetworkOpened,
+            EmberStatus::NetworkClosed => Self::ZigbeeNetworkClosed,
+            EmberStatus::BindingHasChanged => Self::ZigbeeBindingHasChanged,
+            EmberStatus::InsufficientRandomData => Self::ZigbeeInsufficientRandomData,
+            EmberStatus::ApsEncryptionError => Self::ZigbeeApsEncryptionError,
+            EmberStatus::SecurityStateNotSet => Self::ZigbeeSecurityStateNotSet,
+            EmberStatus::SourceRouteFailure => Self::ZigbeeSourceRouteFailure,
+            EmberStatus::ManyToOneRouteFailure => Self::ZigbeeManyToOneRouteFailure,
+            EmberStatus::NoBeacons => Self::NoBeacons,
+            EmberStatus::ReceivedKeyInTheClear => Self::ZigbeeReceivedKeyInTheClear,
+            EmberStatus::NoNetworkKeyReceived => Self::ZigbeeNoNetworkKeyReceived,
+            EmberStatus::NoLinkKeyReceived => Self::ZigbeeNoLinkKeyReceived,
+            EmberStatus::PreconfiguredKeyRequired => Self::ZigbeePreconfiguredKeyRequired,
+            EmberStatus::StackAndHardwareMismatch => Self::ZigbeeStackAndHardwareMismatch,
+            EmberStatus::KeyInvalid => Self::InvalidKey,
+            EmberStatus::LibraryNotPresent => Self::NotAvailable,
+            EmberStatus::TooSoonForSwitchKey => Self::ZigbeeTooSoonForSwitchKey,
+            EmberStatus::SignatureVerifyFailure => Self::ZigbeeSignatureVerifyFailure,
+            EmberStatus::KeyNotAuthorized => Self::ZigbeeKeyNotAuthorized,
+            EmberStatus::TrustCenterEuiHasChanged => Self::ZigbeeTrustCenterSwapEuiHasChanged,
+            EmberStatus::SecurityDataInvalid => Self::InvalidCredentials,
+            EmberStatus::IeeeAddressDiscoveryInProgress => {
+                Self::ZigbeeIeeeAddressDiscoveryInProgress
+            }
+        }
+    }
+}
+
+/// This is not the inverse of the conversion from `EmberStatus`, since it maps many codes onto the
+/// same status, e.g. [`Status::Fail`], [`Status::InvalidParameter`] or [`Status::NotFound`].
+/// For such statuses, a canonical code is picked, e.g. [`EmberStatus::ErrFatal`] for
+/// [`Status::Fail`], so that converting [`EmberStatus::EepromMfgStackVersionMismatch`] to a
+/// status and back yields [`EmberStatus::EepromStackVersionMismatch`].
+/// Statuses without a counterpart are returned as the error.
+impl TryFrom<Status> for EmberStatus {
+    type Error = Status;
+
+    fn try_from(status: Status) -> Result<Self, Self::Error> {
+        match status {
+            Status::Ok => Ok(Self::Success),
+            Status::Fail => Ok(Self::ErrFatal),
+            Status::InvalidParameter => Ok(Self::BadArgument),
+            Status::NotFound => Ok(Self::NotFound),
+            Status::EepromMfgVersionMismatch => Ok(Self::EepromMfgVersionMismatch),
+            Status::EepromStackVersionMismatch => Ok(Self::EepromStackVersionMismatch),
+            Status::AllocationFailed => Ok(Self::NoBuffers),
+            Status::ZigbeePacketHandoffDropped => Ok(Self::PacketHandoffDropPacket),
+            Status::MacNoData => Ok(Self::MacNoData),
+            Status::BadScanDuration => Ok(Self::MacBadScanDuration),
+            Status::MacIncorrectScanType => Ok(Self::MacIncorrectScanType),
+            Status::InvalidChannelMask => Ok(Self::MacInvalidChannelMask),
+            Status::MacCommandTransmitFailure => Ok(Self::MacCommandTransmitFailure),
+            Status::MacTransmitQueueFull => Ok(Self::MacTransmitQueueFull),
+            Status::MacUnknownHeaderType => Ok(Self::MacUnknownHeaderType),
+            Status::MacAckHeaderType => Ok(Self::MacAckHeaderType),
+            Status::MacScanning => Ok(Self::MacScanning),
+            Status::MacNoAckReceived => Ok(Self::MacNoAckReceived),
+            Status::MacIndirectTimeout => Ok(Self::MacIndirectTimeout),
+            Status::FlashWriteInhibited => Ok(Self::ErrFlashWriteInhibited),
+            Status::FlashVerifyFailed => Ok(Self::ErrFlashVerifyFailed),
+            Status::FlashProgramFailed => Ok(Self::ErrFlashProgFail),
+            Status::FlashEraseFailed => Ok(Self::ErrFlashEraseFail),
+            Status::ZigbeeDeliveryFailed => Ok(Self::DeliveryFailed),
+            Status::InvalidState => Ok(Self::InvalidCall),
+            Status::ZigbeeMaxMessageLimitReached => Ok(Self::MaxMessageLimitReached),
+            Status::MessageTooLong => Ok(Self::MessageTooLong),
+            Status::ZigbeeBindingIsActive => Ok(Self::BindingIsActive),
+            Status::ZigbeeAddressTableEntryIsActive => Ok(Self::AddressTableEntryIsActive),
+            Status::TransmitBlocked => Ok(Self::TransmissionSuspended),
+            Status::TransmitSchedulerFail => Ok(Self::PhyTxSchedFail),
+            Status::TransmitUnderflow => Ok(Self::PhyTxUnderflow),
+            Status::TransmitIncomplete => Ok(Self::PhyTxIncomplete),
+            Status::TransmitInvalidChannel => Ok(Self::PhyInvalidChannel),
+            Status::TransmitInvalidPower => Ok(Self::PhyInvalidPower),
+            Status::TransmitBusy => Ok(Self::PhyTxBusy),
+            Status::CcaFailure => Ok(Self::PhyTxCcaFail),
+            Status::TransmitAckReceived => Ok(Self::PhyAckReceived),
+            Status::NetworkUp => Ok(Self::NetworkUp),
+            Status::NetworkDown => Ok(Self::NetworkDown),
+            Status::NotJoined => Ok(Self::NotJoined),
+            Status::ZigbeeInvalidSecurityLevel => Ok(Self::InvalidSecurityLevel),
+            Status::ZigbeeMoveFailed => Ok(Self::MoveFailed),
+            Status::ZigbeeNodeIdChanged => Ok(Self::NodeIdChanged),
+            Status::ZigbeePanIdChanged => Ok(Self::PanIdChanged),
+            Status::ZigbeeChannelChanged => Ok(Self::ChannelChanged),
+            Status::ZigbeeNetworkOpened => Ok(Self::NetworkOpened),
+            Status::ZigbeeNetworkClosed => Ok(Self::NetworkClosed),
+            Status::Busy => Ok(Self::NetworkBusy),
+            Status::ZigbeeBindingHasChanged => Ok(Self::BindingHasChanged),
+            Status::ZigbeeInsufficientRandomData => Ok(Self::InsufficientRandomData),
+            Status::ZigbeeApsEncryptionError => Ok(Self::ApsEncryptionError),
+            Status::ZigbeeSecurityStateNotSet => Ok(Self::SecurityStateNotSet),
+            Status::ZigbeeSourceRouteFailure => Ok(Self::SourceRouteFailure),
+            Status::ZigbeeManyToOneRouteFailure => Ok(Self::ManyToOneRouteFailure),
+            Status::NoBeacons => Ok(Self::NoBeacons),
+            Status::ZigbeeReceivedKeyInTheClear => Ok(Self::ReceivedKeyInTheClear),
+            Status::ZigbeeNoNetworkKeyReceived => Ok(Self::NoNetworkKeyReceived),
+            Status::ZigbeeNoLinkKeyReceived => Ok(Self::NoLinkKeyReceived),
+            Status::ZigbeePreconfiguredKeyRequired => Ok(Self::PreconfiguredKeyRequired),
+            Status::ZigbeeStackAndHardwareMismatch => Ok(Self::StackAndHardwareMismatch),
+            Status::InvalidIndex => Ok(Self::IndexOutOfRange),
+            Status::InvalidKey => Ok(Self::KeyInvalid),
+            Status::StatusFull => Ok(Self::TableFull),
+            Status::NotAvailable => Ok(Self::LibraryNotPresent),
+            Status::ZigbeeTooSoonForSwitchKey => Ok(Self::TooSoonForSwitchKey),
+            Status::ZigbeeSignatureVerifyFailure => Ok(Self::SignatureVerifyFailure),
+            Status::InProgress => Ok(Self::OperationInProgress),
+            Status::ZigbeeKeyNotAuthorized => Ok(Self::KeyNotAuthorized),
+            Status::ZigbeeTrustCenterSwapEuiHasChanged => Ok(Self::TrustCenterEuiHasChanged),
+            Status::ZigbeeIeeeAddressDiscoveryInProgress => {
+                Ok(Self::IeeeAddressDiscoveryInProgress)
+            }
+            other => Err(other),
+        }
+    }
+}
+
+impl Error for EmberStatus {}
+
+#[cfg(test)]
+mod tests {
+    use super::{EmberStatus, Status};
+
+    #[test]
+    fn status_round_trip() {
+        for status in Status::iter() {
+            if let Ok(ember_status) = EmberStatus::try_from(status) {
+                assert_eq!(Status::from(ember_status), status, "{ember_status:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn ember_status_round_trip() {
+        for ember_status in EmberStatus::iter() {
+            let status = Status::from(ember_status);
+
+            if let Ok(canonical) = EmberStatus::try_from(status) {
+                assert_eq!(Status::from(canonical), status, "{ember_status:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn canonical_codes() {
+        assert_eq!(
+            EmberStatus::try_from(Status::from(EmberStatus::ErrFatal)),
+            Ok(EmberStatus::ErrFatal)
+        );
+        assert_eq!(
+            EmberStatus::try_from(Status::from(EmberStatus::ApplicationError0)),
+            Ok(EmberStatus::ErrFatal)
+        );
+        assert_eq!(
+            EmberStatus::try_from(Status::from(EmberStatus::EepromMfgStackVersionMismatch)),
+            Ok(EmberStatus::EepromStackVersionMismatch)
+        );
+        assert_eq!(
+            EmberStatus::try_from(Status::from(EmberStatus::SerialInvalidPort)),
+            Ok(EmberStatus::BadArgument)
+        );
+    }
+
+    #[test]
+    fn unmapped_statuses() {
+        for status in [
+            Status::Timeout,
+            Status::InvalidConfiguration,
+            Status::Receive,
+        ] {
+            assert_eq!(EmberStatus::try_from(status), Err(status));
+        }
+    }
+}
@@ -0,0 +1,528 @@
+use core::error::Error;
+
+use num_derive::FromPrimitive;
+use num_traits::FromPrimitive;
+
+pub use self::layer::EzspLayer;
+use crate::macros::sdk_enum;
+
+mod layer;
+
+sdk_enum! {
+    prefix: "EZSP_",
+    noun: "EZSP status",
+    repr: u8,
+
+    /// Status codes of the EZSP host, covering the serial transport and the EZSP protocol itself.
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Ord, PartialOrd, FromPrimitive)]
+    pub enum EzspStatus {
+        /// Success.
+        Success = 0x00,
+
+        /// Fatal error.
+        SpiErrFatal = 0x10,
+
+        /// The response frame of the current transaction indicates the NCP has reset.
+        SpiErrNcpReset = 0x11,
+
+        /// The NCP is reporting that the command frame of the current transaction is oversized (the
+        /// length byte is too large).
+        SpiErrOversizedEzspFrame = 0x12,
+
+        /// The response frame of the current transaction indicates the previous transaction was
+        /// aborted (`nSSEL` deasserted too soon).
+        SpiErrAbortedTransaction = 0x13,
+
+        /// The response frame of the current transaction indicates the frame terminator is missing
+        /// from the command frame.
+        SpiErrMissingFrameTerminator = 0x14,
+
+        /// The NCP has not provided a response within the time limit defined by
+        /// `WAIT_SECTION_TIMEOUT`.
+        SpiErrWaitSectionTimeout = 0x15,
+
+        /// The response frame from the NCP is missing the frame terminator.
+        SpiErrNoFrameTerminator = 0x16,
+
+        /// The host attempted to send an oversized command (the length byte is too large) and the
+        /// transmission was blocked.
+        SpiErrEzspCommandOversized = 0x17,
+
+        /// The NCP attempted to send an oversized response (the length byte is too large) and the
+        /// reception was blocked.
+        SpiErrEzspResponseOversized = 0x18,
+
+        /// The host has sent the command and is still waiting for the NCP to send a response.
+        SpiWaitingForResponse = 0x19,
+
+        /// The NCP has not asserted `nHOST_INT` within the time limit defined by
+        /// `WAKE_HANDSHAKE_TIMEOUT`.
+        SpiErrHandshakeTimeout = 0x1A,
+
+        /// The NCP has not asserted `nHOST_INT` after an NCP reset within the time limit defined by
+        /// `STARTUP_TIMEOUT`.
+        SpiErrStartupTimeout = 0x1B,
+
+        /// The host attempted to verify the SPI protocol activity and version number, and the
+        /// verification failed.
+        SpiErrStartupFail = 0x1C,
+
+        /// The host has sent a command with a SPI byte that is unsupported by the current mode the
+        /// NCP is operating in.
+        SpiErrUnsupportedSpiCommand = 0x1D,
+
+        /// Operation not yet complete.
+        AshInProgress = 0x20,
+
+        /// Fatal error detected by the host.
+        HostFatalError = 0x21,
+
+        /// Fatal error detected by the NCP.
+        AshNcpFatalError = 0x22,
+
+        /// Tried to send a DATA frame which is too long.
+        DataFrameTooLong = 0x23,
+
+        /// Tried to send a DATA frame which is too short.
+        DataFrameTooShort = 0x24,
+
+        /// No space for a transmitted DATA frame.
+        NoTxSpace = 0x25,
+
+        /// No space for a received DATA frame.
+        NoRxSpace = 0x26,
+
+        /// No receive data available.
+        NoRxData = 0x27,
+
+        /// Not in connected state.
+        NotConnected = 0x28,
+
+        /// The NCP received a command before the EZSP version had been set.
+        ErrorVersionNotSet = 0x30,
+
+        /// The NCP received a command containing an unsupported frame ID.
+        ErrorInvalidFrameId = 0x31,
+
+        /// The direction flag in the frame control field was incorrect.
+        ErrorWrongDirection = 0x32,
+
+        /// The truncated flag in the frame control field was set, indicating there was not enough
+        /// memory available to complete the response or that the response would have exceeded the
+        /// maximum EZSP frame length.
+        ErrorTruncated = 0x33,
+
+        /// The overflow flag in the frame control field was set, indicating one or more callbacks
+        /// occurred since the previous response and there was not enough memory available to report
+        /// them to the host.
+        ErrorOverflow = 0x34,
+
+        /// Insufficient memory was available.
+        ErrorOutOfMemory = 0x35,
+
+        /// The value was out of bounds.
+        ErrorInvalidValue = 0x36,
+
+        /// The configuration ID was not recognized.
+        ErrorInvalidId = 0x37,
+
+        /// Configuration values can no longer be modified.
+        ErrorInvalidCall = 0x38,
+
+        /// The NCP failed to respond to a command.
+        ErrorNoResponse = 0x39,
+
+        /// The length of the command exceeded the maximum EZSP frame length.
+        ErrorCommandTooLong = 0x40,
+
+        /// The UART receive queue was full causing a callback response to be dropped.
+        ErrorQueueFull = 0x41,
+
+        /// The command has been filtered out by the NCP.
+        ErrorCommandFiltered = 0x42,
+
+        /// The EZSP security key is already set.
+        ErrorSecurityKeyAlreadySet = 0x43,
+
+        /// The EZSP security type is invalid.
+        ErrorSecurityTypeInvalid = 0x44,
+
+        /// The EZSP security parameters are invalid.
+        ErrorSecurityParametersInvalid = 0x45,
+
+        /// The EZSP security parameters are already set.
+        ErrorSecurityParametersAlreadySet = 0x46,
+
+        /// The EZSP security key is not set.
+        ErrorSecurityKeyNotSet = 0x47,
+
+        /// The EZSP security parameters are not set.
+        ErrorSecurityParametersNotSet = 0x48,
+
+        /// Received a frame with an unsupported control byte.
+        ErrorUnsupportedControl = 0x49,
+
+        /// Received an unsecure frame while security is established.
+        ErrorUnsecureFrame = 0x4A,
+
+        /// Incompatible ASH version.
+        AshErrorVersion = 0x50,
+
+        /// Exceeded the maximum number of ACK timeouts.
+        AshErrorTimeouts = 0x51,
+
+        /// Timed out waiting for RSTACK.
+        AshErrorResetFail = 0x52,
+
+        /// Unexpected NCP reset.
+        AshErrorNcpReset = 0x53,
+
+        /// Serial port initialization failed.
+        ErrorSerialInit = 0x54,
+
+        /// Invalid NCP processor type.
+        AshErrorNcpType = 0x55,
+
+        /// Invalid NCP reset method.
+        AshErrorResetMethod = 0x56,
+
+        /// XON/XOFF is not supported by the host driver.
+        AshErrorXonXoff = 0x57,
+
+        /// ASH protocol started.
+        AshStarted = 0x70,
+
+        /// ASH protocol connected.
+        AshConnected = 0x71,
+
+        /// ASH protocol disconnected.
+        AshDisconnected = 0x72,
+
+        /// Timer expired waiting for an ACK.
+        AshAckTimeout = 0x73,
+
+        /// Frame in progress cancelled.
+        AshCancelled = 0x74,
+
+        /// Received a frame out of sequence.
+        AshOutOfSequence = 0x75,
+
+        /// Received a frame with a CRC error.
+        AshBadCrc = 0x76,
+
+        /// Received a frame with a communication error.
+        AshCommError = 0x77,
+
+        /// Received a frame with a bad ACK number.
+        AshBadAcknum = 0x78,
+
+        /// Received a frame shorter than the minimum.
+        AshTooShort = 0x79,
+
+        /// Received a frame longer than the maximum.
+        AshTooLong = 0x7A,
+
+        /// Received a frame with an illegal control byte.
+        AshBadControl = 0x7B,
+
+        /// Received a frame with an illegal length for its type.
+        AshBadLength = 0x7C,
+
+        /// Received an ASH ACK.
+        AshAckReceived = 0x7D,
+
+        /// Sent an ASH ACK.
+        AshAckSent = 0x7E,
+
+        /// Received an ASH NAK.
+        AshNakReceived = 0x7F,
+
+        /// Sent an ASH NAK.
+        AshNakSent = 0x80,
+
+        /// Received an ASH RST.
+        AshRstReceived = 0x81,
+
+        /// Sent an ASH RST.
+        AshRstSent = 0x82,
+
+        /// ASH status.
+        AshStatus = 0x83,
+
+        /// ASH transmission.
+        AshTx = 0x84,
+
+        /// ASH reception.
+        AshRx = 0x85,
+
+        /// Failed to connect to the CPC daemon or to open the CPC endpoint.
+        CpcErrorInit = 0x86,
+
+        /// No reset or error.
+        NoError = 0xFF,
+    }
+}
+
+impl EzspStatus {
+    /// Returns the layer which reported the status.
+    ///
+    /// Returns [`None`] for [`EzspStatus::Success`] and [`EzspStatus::NoError`].
+    #[must_use]
+    pub const fn layer(self) -> Option<EzspLayer> {
+        match self as u8 {
+            0x10..=0x1F => Some(EzspLayer::Spi),
+            0x20..=0x2F | 0x50..=0x57 | 0x70..=0x85 => Some(EzspLayer::Ash),
+            0x30..=0x4F => Some(EzspLayer::Protocol),
+            0x86 => Some(EzspLayer::Cpc),
+            _ => None,
+        }
+    }
+
+    /// Determines whether the status was reported by the ASH serial protocol.
+    #[must_use]
+    pub const fn is_ash(self) -> bool {
+        matches!(self.layer(), Some(EzspLayer::Ash))
+    }
+
+    /// Determines whether the status was reported by the SPI protocol.
+    #[must_use]
+    pub const fn is_spi(self) -> bool {
+        matches!(self.layer(), Some(EzspLayer::Spi))
+    }
+
+    /// Determines whether the status was reported by the EZSP protocol.
+    #[must_use]
+    pub const fn is_protocol(self) -> bool {
+        matches!(self.layer(), Some(EzspLayer::Protocol))
+    }
+
+    /// Return the name of the status.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Success => "EZSP_SUCCESS",
+            Self::SpiErrFatal => "EZSP_SPI_ERR_FATAL",
+            Self::SpiErrNcpReset => "EZSP_SPI_ERR_NCP_RESET",
+            Self::SpiErrOversizedEzspFrame => "EZSP_SPI_ERR_OVERSIZED_EZSP_FRAME",
+            Self::SpiErrAbortedTransaction => "EZSP_SPI_ERR_ABORTED_TRANSACTION",
+            Self::SpiErrMissingFrameTerminator => "EZSP_SPI_ERR_MISSING_FRAME_TERMINATOR",
+            Self::SpiErrWaitSectionTimeout => "EZSP_SPI_ERR_WAIT_SECTION_TIMEOUT",
+            Self::SpiErrNoFrameTerminator => "EZSP_SPI_ERR_NO_FRAME_TERMINATOR",
+            Self::SpiErrEzspCommandOversized => "EZSP_SPI_ERR_EZSP_COMMAND_OVERSIZED",
+            Self::SpiErrEzspResponseOversized => "EZSP_SPI_ERR_EZSP_RESPONSE_OVERSIZED",
+            Self::SpiWaitingForResponse => "EZSP_SPI_WAITING_FOR_RESPONSE",
+            Self::SpiErrHandshakeTimeout => "EZSP_SPI_ERR_HANDSHAKE_TIMEOUT",
+            Self::SpiErrStartupTimeout => "EZSP_SPI_ERR_STARTUP_TIMEOUT",
+            Self::SpiErrStartupFail => "EZSP_SPI_ERR_STARTUP_FAIL",
+            Self::SpiErrUnsupportedSpiCommand => "EZSP_SPI_ERR_UNSUPPORTED_SPI_COMMAND",
+            Self::AshInProgress => "EZSP_ASH_IN_PROGRESS",
+            Self::HostFatalError => "EZSP_HOST_FATAL_ERROR",
+            Self::AshNcpFatalError => "EZSP_ASH_NCP_FATAL_ERROR",
+            Self::DataFrameTooLong => "EZSP_DATA_FRAME_TOO_LONG",
+            Self::DataFrameTooShort => "EZSP_DATA_FRAME_TOO_SHORT",
+            Self::NoTxSpace => "EZSP_NO_TX_SPACE",
+            Self::NoRxSpace => "EZSP_NO_RX_SPACE",
+            Self::NoRxData => "EZSP_NO_RX_DATA",
+            Self::NotConnected => "EZSP_NOT_CONNECTED",
+            Self::ErrorVersionNotSet => "EZSP_ERROR_VERSION_NOT_SET",
+            Self::ErrorInvalidFrameId => "EZSP_ERROR_INVALID_FRAME_ID",
+            Self::ErrorWrongDirection => "EZSP_ERROR_WRONG_DIRECTION",
+            Self::ErrorTruncated => "EZSP_ERROR_TRUNCATED",
+            Self::ErrorOverflow => "EZSP_ERROR_OVERFLOW",
+            Self::ErrorOutOfMemory => "EZSP_ERROR_OUT_OF_MEMORY",
+            Self::ErrorInvalidValue => "EZSP_ERROR_INVALID_VALUE",
+            Self::ErrorInvalidId => "EZSP_ERROR_INVALID_ID",
+            Self::ErrorInvalidCall => "EZSP_ERROR_INVALID_CALL",
+            Self::ErrorNoResponse => "EZSP_ERROR_NO_RESPONSE",
+            Self::ErrorCommandTooLong => "EZSP_ERROR_COMMAND_TOO_LONG",
+            Self::ErrorQueueFull => "EZSP_ERROR_QUEUE_FULL",
+            Self::ErrorCommandFiltered => "EZSP_ERROR_COMMAND_FILTERED",
+            Self::ErrorSecurityKeyAlreadySet => "EZSP_ERROR_SECURITY_KEY_ALREADY_SET",
+            Self::ErrorSecurityTypeInvalid => "EZSP_ERROR_SECURITY_TYPE_INVALID",
+            Self::ErrorSecurityParametersInvalid => "EZSP_ERROR_SECURITY_PARAMETERS_INVALID",
+            Self::ErrorSecurityParametersAlreadySet => "EZSP_ERROR_SECURITY_PARAMETERS_ALREADY_SET",
+            Self::ErrorSecurityKeyNotSet => "EZSP_ERROR_SECURITY_KEY_NOT_SET",
+            Self::ErrorSecurityParametersNotSet => "EZSP_ERROR_SECURITY_PARAMETERS_NOT_SET",
+            Self::ErrorUnsupportedControl => "EZSP_ERROR_UNSUPPORTED_CONTROL",
+            Self::ErrorUnsecureFrame => "EZSP_ERROR_UNSECURE_FRAME",
+            Self::AshErrorVersion => "EZSP_ASH_ERROR_VERSION",
+            Self::AshErrorTimeouts => "EZSP_ASH_ERROR_TIMEOUTS",
+            Self::AshErrorResetFail => "EZSP_ASH_ERROR_RESET_FAIL",
+            Self::AshErrorNcpReset => "EZSP_ASH_ERROR_NCP_RESET",
+            Self::ErrorSerialInit => "EZSP_ERROR_SERIAL_INIT",
+            Self::AshErrorNcpType => "EZSP_ASH_ERROR_NCP_TYPE",
+            Self::AshErrorResetMethod => "EZSP_ASH_ERROR_RESET_METHOD",
+            Self::AshErrorXonXoff => "EZSP_ASH_ERROR_XON_XOFF",
+            Self::AshStarted => "EZSP_ASH_STARTED",
+            Self::AshConnected => "EZSP_ASH_CONNECTED",
+            Self::AshDisconnected => "EZSP_ASH_DISCONNECTED",
+            Self::AshAckTimeout => "EZSP_ASH_ACK_TIMEOUT",
+            Self::AshCancelled => "EZSP_ASH_CANCELLED",
+            Self::AshOutOfSequence => "EZSP_ASH_OUT_OF_SEQUENCE",
+            Self::AshBadCrc => "EZSP_ASH_BAD_CRC",
+            Self::AshCommError => "EZSP_ASH_COMM_ERROR",
+            Self::AshBadAcknum => "EZSP_ASH_BAD_ACKNUM",
+            Self::AshTooShort => "EZSP_ASH_TOO_SHORT",
+            Self::AshTooLong => "EZSP_ASH_TOO_LONG",
+            Self::AshBadControl => "EZSP_ASH_BAD_CONTROL",
+            Self::AshBadLength => "EZSP_ASH_BAD_LENGTH",
+            Self::AshAckReceived => "EZSP_ASH_ACK_RECEIVED",
+            Self::AshAckSent => "EZSP_ASH_ACK_SENT",
+            Self::AshNakReceived => "EZSP_ASH_NAK_RECEIVED",
+            Self::AshNakSent => "EZSP_ASH_NAK_SENT",
+            Self::AshRstReceived => "EZSP_ASH_RST_RECEIVED",
+            Self::AshRstSent => "EZSP_ASH_RST_SENT",
+            Self::AshStatus => "EZSP_ASH_STATUS",
+            Self::AshTx => "EZSP_ASH_TX",
+            Self::AshRx => "EZSP_ASH_RX",
+            Self::CpcErrorInit => "EZSP_CPC_ERROR_INIT",
+            Self::NoError => "EZSP_NO_ERROR",
+        }
+    }
+
+    /// Return a human-readable description of the status.
+    #[expect(clippy::too_many_lines)]
+    #[must_use]
+    pub const fn description(self) -> &'static str {
+        match self {
+            Self::Success => "Success.",
+            Self::SpiErrFatal => "Fatal error.",
+            Self::SpiErrNcpReset => {
+                "The response frame of the current transaction indicates the NCP has reset."
+            }
+            Self::SpiErrOversizedEzspFrame => {
+                "The NCP is reporting that the command frame of the current transaction is oversized (the length byte is too large)."
+            }
+            Self::SpiErrAbortedTransaction => {
+                "The response frame of the current transaction indicates the previous transaction was aborted (nSSEL deasserted too soon)."
+            }
+            Self::SpiErrMissingFrameTerminator => {
+                "The response frame of the current transaction indicates the frame terminator is missing from the command frame."
+            }
+            Self::SpiErrWaitSectionTimeout => {
+                "The NCP has not provided a response within the time limit defined by WAIT_SECTION_TIMEOUT."
+            }
+            Self::SpiErrNoFrameTerminator => {
+                "The response frame from the NCP is missing the frame terminator."
+            }
+            Self::SpiErrEzspCommandOversized => {
+                "The host attempted to send an oversized command (the length byte is too large) and the transmission was blocked."
+            }
+            Self::SpiErrEzspResponseOversized => {
+                "The NCP attempted to send an oversized response (the length byte is too large) and the reception was blocked."
+            }
+            Self::SpiWaitingForResponse => {
+                "The host has sent the command and is still waiting for the NCP to send a response."
+            }
+            Self::SpiErrHandshakeTimeout => {
+                "The NCP has not asserted nHOST_INT within the time limit defined by WAKE_HANDSHAKE_TIMEOUT."
+            }
+            Self::SpiErrStartupTimeout => {
+                "The NCP has not asserted nHOST_INT after an NCP reset within the time limit defined by STARTUP_TIMEOUT."
+            }
+            Self::SpiErrStartupFail => {
+                "The host attempted to verify the SPI protocol activity and version number, and the verification failed."
+            }
+            Self::SpiErrUnsupportedSpiCommand => {
+                "The host has sent a command with a SPI byte that is unsupported by the current mode the NCP is operating in."
+            }
+            Self::AshInProgress => "Operation not yet complete.",
+            Self::HostFatalError => "Fatal error detected by the host.",
+            Self::AshNcpFatalError => "Fatal error detected by the NCP.",
+            Self::DataFrameTooLong => "Tried to send a DATA frame which is too long.",
+            Self::DataFrameTooShort => "Tried to send a DATA frame which is too short.",
+            Self::NoTxSpace => "No space for a transmitted DATA frame.",
+            Self::NoRxSpace => "No space for a received DATA frame.",
+            Self::NoRxData => "No receive data available.",
+            Self::NotConnected => "Not in connected state.",
+            Self::ErrorVersionNotSet => {
+                "The NCP received a command before the EZSP version had been set."
+            }
+            Self::ErrorInvalidFrameId => {
+                "The NCP received a command containing an unsupported frame ID."
+            }
+            Self::ErrorWrongDirection => {
+                "The direction flag in the frame control field was incorrect."
+            }
+            Self::ErrorTruncated => {
+                "The truncated flag in the frame control field was set, indicating there was not enough memory available to complete the response or that the response would have exceeded the maximum EZSP frame length."
+            }
+            Self::ErrorOverflow => {
+                "The overflow flag in the frame control field was set, indicating one or more callbacks occurred since the previous response and there was not enough memory available to report them to the host."
+            }
+            Self::ErrorOutOfMemory => "Insufficient memory was available.",
+            Self::ErrorInvalidValue => "The value was out of bounds.",
+            Self::ErrorInvalidId => "The configuration ID was not recognized.",
+            Self::ErrorInvalidCall => "Configuration values can no longer be modified.",
+            Self::ErrorNoResponse => "The NCP failed to respond to a command.",
+            Self::ErrorCommandTooLong => {
+                "The length of the command exceeded the maximum EZSP frame length."
+            }
+            Self::ErrorQueueFull => {
+                "The UART receive queue was full causing a callback response to be dropped."
+            }
+            Self::ErrorCommandFiltered => "The command has been filtered out by the NCP.",
+            Self::ErrorSecurityKeyAlreadySet => "The EZSP security key is already set.",
+            Self::ErrorSecurityTypeInvalid => "The EZSP security type is invalid.",
+            Self::ErrorSecurityParametersInvalid => "The EZSP security parameters are invalid.",
+            Self::ErrorSecurityParametersAlreadySet => {
+                "The EZSP security parameters are already set."
+            }
+            Self::ErrorSecurityKeyNotSet => "The EZSP security key is not set.",
+            Self::ErrorSecurityParametersNotSet => "The EZSP security parameters are not set.",
+            Self::ErrorUnsupportedControl => "Received a frame with an unsupported control byte.",
+            Self::ErrorUnsecureFrame => "Received an unsecure frame while security is established.",
+            Self::AshErrorVersion => "Incompatible ASH version.",
+            Self::AshErrorTimeouts => "Exceeded the maximum number of ACK timeouts.",
+            Self::AshErrorResetFail => "Timed out waiting for RSTACK.",
+            Self::AshErrorNcpReset => "Unexpected NCP reset.",
+            Self::ErrorSerialInit => "Serial port initialization failed.",
+            Self::AshErrorNcpType => "Invalid NCP processor type.",
+            Self::AshErrorResetMethod => "Invalid NCP reset method.",
+            Self::AshErrorXonXoff => "XON/XOFF is not supported by the host driver.",
+            Self::AshStarted => "ASH protocol started.",
+            Self::AshConnected => "ASH protocol connected.",
+            Self::AshDisconnected => "ASH protocol disconnected.",
+            Self::AshAckTimeout => "Timer expired waiting for an ACK.",
+            Self::AshCancelled => "Frame in progress cancelled.",
+            Self::AshOutOfSequence => "Received a frame out of sequence.",
+            Self::AshBadCrc => "Received a frame with a CRC error.",
+            Self::AshCommError => "Received a frame with a communication error.",
+            Self::AshBadAcknum => "Received a frame with a bad ACK number.",
+            Self::AshTooShort => "Received a frame shorter than the minimum.",
+            Self::AshTooLong => "Received a frame longer than the maximum.",
+            Self::AshBadControl => "Received a frame with an illegal control byte.",
+            Self::AshBadLength => "Received a frame with an illegal length for its type.",
+            Self::AshAckReceived => "Received an ASH ACK.",
+            Self::AshAckSent => "Sent an ASH ACK.",
+            Self::AshNakReceived => "Received an ASH NAK.",
+            Self::AshNakSent => "Sent an ASH NAK.",
+            Self::AshRstReceived => "Received an ASH RST.",
+            Self::AshRstSent => "Sent an ASH RST.",
+            Self::AshStatus => "ASH status.",
+            Self::AshTx => "ASH transmission.",
+            Self::AshRx => "ASH reception.",
+            Self::CpcErrorInit => {
+                "Failed to connect to the CPC daemon or to open the CPC endpoint."
+            }
+            Self::NoError => "No reset or error.",
+        }
+    }
+}
+
+impl From<EzspStatus> for u8 {
+    fn from(status: EzspStatus) -> Self {
+        status as Self
+    }
+}
+
+impl TryFrom<u8> for EzspStatus {
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Self::from_u8(value).ok_or(value)
+    }
+}
+
+impl Error for EzspStatus {}
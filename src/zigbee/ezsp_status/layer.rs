@@ -0,0 +1,17 @@
+/// Layers of the host-to-NCP stack which report [`EzspStatus`](super::EzspStatus) codes.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Ord, PartialOrd)]
+pub enum EzspLayer {
+    /// The SPI protocol used to talk to an NCP over SPI.
+    Spi,
+
+    /// The ASH protocol used to talk to an NCP over UART.
+    Ash,
+
+    /// The CPC protocol used to talk to an NCP through the CPC daemon.
+    Cpc,
+
+    /// The EZSP protocol itself, independent of the transport.
+    Protocol,
+}
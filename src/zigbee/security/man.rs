@@ -1,17 +1,23 @@
 //! Security manager module.
 
 pub use self::aps_key_metadata::ApsKeyMetadata;
-pub use self::context::Context;
+pub use self::context::{Context, ContextBuilder};
 pub use self::derived_key_type::DerivedKeyType;
+pub use self::derived_key_types::DerivedKeyTypes;
 pub use self::flags::Flags;
 pub use self::key::Key;
+pub use self::key_struct_bitmask::KeyStructBitmask;
 pub use self::key_type::KeyType;
 pub use self::network_key_info::NetworkKeyInfo;
+pub use self::validated_context::{InvalidContext, ValidatedContext};
 
 mod aps_key_metadata;
 mod context;
 mod derived_key_type;
+mod derived_key_types;
 mod flags;
 mod key;
+mod key_struct_bitmask;
 mod key_type;
 mod network_key_info;
+mod validated_context;
@@ -1,6 +1,9 @@
 use core::time::Duration;
 
+use crate::zigbee::security::man::KeyStructBitmask;
+
 /// This data structure contains the metadata pertaining to an APS key.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(
     feature = "le-stream",
@@ -8,7 +11,7 @@ use core::time::Duration;
 )]
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct ApsKeyMetadata {
-    bitmask: u16,
+    bitmask: KeyStructBitmask,
     outgoing_frame_counter: u32,
     incoming_frame_counter: u32,
     ttl_in_seconds: u16,
@@ -18,7 +21,7 @@ impl ApsKeyMetadata {
     /// Creates a new `ApsKeyMetadata`.
     #[must_use]
     pub const fn new(
-        bitmask: u16,
+        bitmask: KeyStructBitmask,
         outgoing_frame_counter: u32,
         incoming_frame_counter: u32,
         ttl_in_seconds: u16,
@@ -33,7 +36,7 @@ impl ApsKeyMetadata {
 
     /// Returns the bitmask.
     #[must_use]
-    pub const fn bitmask(&self) -> u16 {
+    pub const fn bitmask(&self) -> KeyStructBitmask {
         self.bitmask
     }
 
@@ -43,12 +46,38 @@ impl ApsKeyMetadata {
         self.outgoing_frame_counter
     }
 
+    /// Returns the outgoing frame counter, if the bitmask marks it as valid.
+    #[must_use]
+    pub const fn valid_outgoing_frame_counter(&self) -> Option<u32> {
+        if self
+            .bitmask
+            .contains(KeyStructBitmask::HAS_OUTGOING_FRAME_COUNTER)
+        {
+            Some(self.outgoing_frame_counter)
+        } else {
+            None
+        }
+    }
+
     /// Returns the incoming frame counter.
     #[must_use]
     pub const fn incoming_frame_counter(&self) -> u32 {
         self.incoming_frame_counter
     }
 
+    /// Returns the incoming frame counter, if the bitmask marks it as valid.
+    #[must_use]
+    pub const fn valid_incoming_frame_counter(&self) -> Option<u32> {
+        if self
+            .bitmask
+            .contains(KeyStructBitmask::HAS_INCOMING_FRAME_COUNTER)
+        {
+            Some(self.incoming_frame_counter)
+        } else {
+            None
+        }
+    }
+
     /// Returns the time-to-live in seconds.
     #[must_use]
     pub const fn ttl_in_seconds(&self) -> u16 {
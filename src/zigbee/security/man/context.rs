@@ -1,9 +1,16 @@
 use macaddr::MacAddr8;
-use num_traits::FromPrimitive;
 
-use crate::zigbee::security::man::{DerivedKeyType, Flags, KeyType};
+pub use self::builder::ContextBuilder;
+use crate::zigbee::security::man::{DerivedKeyTypes, Flags, KeyType};
+
+mod builder;
 
 /// Context for Zigbee Security Manager operations.
+///
+/// The context mirrors the wire format and retains the derived key types as received, even if they
+/// are not [valid](DerivedKeyTypes::is_valid). Convert it into a [`ValidatedContext`] to validate it.
+///
+/// [`ValidatedContext`]: crate::zigbee::security::man::ValidatedContext
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(
     feature = "le-stream",
@@ -11,9 +18,9 @@ use crate::zigbee::security::man::{DerivedKeyType, Flags, KeyType};
 )]
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct Context {
-    core_key_type: u8,
+    core_key_type: KeyType,
     key_index: u8,
-    derived_type: u8,
+    derived_type: DerivedKeyTypes,
     eui64: MacAddr8,
     multi_network_index: u8,
     flags: Flags,
@@ -21,21 +28,24 @@ pub struct Context {
 }
 
 impl Context {
+    /// The PSA algorithm set by `sl_zb_sec_man_init_context`, i.e. `PSA_ALG_ECB_NO_PADDING`.
+    pub const DEFAULT_PSA_KEY_ALG_PERMISSION: u32 = 0x0440_4400;
+
     /// Creates a new `Context`.
     #[must_use]
     pub const fn new(
         core_key_type: KeyType,
         key_index: u8,
-        derived_type: DerivedKeyType,
+        derived_type: DerivedKeyTypes,
         eui64: MacAddr8,
         multi_network_index: u8,
         flags: Flags,
         psa_key_alg_permission: u32,
     ) -> Self {
         Self {
-            core_key_type: core_key_type as u8,
+            core_key_type,
             key_index,
-            derived_type: derived_type as u8,
+            derived_type,
             eui64,
             multi_network_index,
             flags,
@@ -43,13 +53,64 @@ impl Context {
         }
     }
 
+    /// Returns a builder for a `Context`.
+    #[must_use]
+    pub const fn builder() -> ContextBuilder {
+        ContextBuilder::new()
+    }
+
+    /// Creates a `Context` referencing the network key.
+    #[must_use]
+    pub const fn network_key() -> Self {
+        ContextBuilder::new()
+            .core_key_type(KeyType::Network)
+            .build()
+    }
+
+    /// Creates a `Context` referencing the Trust Center Link Key.
+    #[must_use]
+    pub const fn tc_link_key() -> Self {
+        ContextBuilder::new().core_key_type(KeyType::TcLink).build()
+    }
+
+    /// Creates a `Context` referencing the application link key shared with the given EUI64.
+    #[must_use]
+    pub const fn app_link_key(eui64: MacAddr8) -> Self {
+        ContextBuilder::new()
+            .core_key_type(KeyType::AppLink)
+            .eui64(eui64)
+            .build()
+    }
+
+    /// Creates a `Context` referencing the transient key at the given index.
+    #[must_use]
+    pub const fn transient_key(key_index: u8) -> Self {
+        ContextBuilder::new()
+            .core_key_type(KeyType::TcLinkWithTimeout)
+            .key_index(key_index)
+            .build()
+    }
+
+    /// Creates a `Context` referencing the ZLL encryption key.
+    #[must_use]
+    pub const fn zll_encryption_key() -> Self {
+        ContextBuilder::new()
+            .core_key_type(KeyType::ZllEncryptionKey)
+            .build()
+    }
+
+    /// Creates a `Context` referencing the ZLL preconfigured key.
+    #[must_use]
+    pub const fn zll_preconfigured_key() -> Self {
+        ContextBuilder::new()
+            .core_key_type(KeyType::ZllPreconfiguredKey)
+            .build()
+    }
+
     /// Returns the type of key being referenced.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the key type is not recognized.
-    pub fn core_key_type(&self) -> Result<KeyType, u8> {
-        KeyType::from_u8(self.core_key_type).ok_or(self.core_key_type)
+    #[must_use]
+    pub const fn core_key_type(&self) -> KeyType {
+        self.core_key_type
     }
 
     /// Returns the index of the referenced key.
@@ -58,13 +119,12 @@ impl Context {
         self.key_index
     }
 
-    /// Returns the type of key derivation operation to perform on a key.
-    ///
-    /// # Errors
+    /// Returns the key derivation operations to perform on a key.
     ///
-    /// Returns an error if the derived key type is not recognized.
-    pub fn derived_type(&self) -> Result<DerivedKeyType, u8> {
-        DerivedKeyType::from_u8(self.derived_type).ok_or(self.derived_type)
+    /// The derived key types are not validated, see [`DerivedKeyTypes::is_valid()`].
+    #[must_use]
+    pub const fn derived_type(&self) -> DerivedKeyTypes {
+        self.derived_type
     }
 
     /// Return the EUI64 associated with this key.
@@ -90,4 +150,117 @@ impl Context {
     pub const fn psa_key_alg_permission(&self) -> u32 {
         self.psa_key_alg_permission
     }
+
+    /// Sets the type of key being referenced.
+    pub const fn set_core_key_type(&mut self, core_key_type: KeyType) {
+        self.core_key_type = core_key_type;
+    }
+
+    /// Sets the type of key being referenced and returns the `Context`.
+    #[must_use]
+    pub const fn with_core_key_type(mut self, core_key_type: KeyType) -> Self {
+        self.set_core_key_type(core_key_type);
+        self
+    }
+
+    /// Sets the index of the referenced key and marks it as valid.
+    pub const fn set_key_index(&mut self, key_index: u8) {
+        self.key_index = key_index;
+        self.flags = self.flags.union(Flags::KEY_INDEX_IS_VALID);
+    }
+
+    /// Sets the index of the referenced key, marks it as valid and returns the `Context`.
+    #[must_use]
+    pub const fn with_key_index(mut self, key_index: u8) -> Self {
+        self.set_key_index(key_index);
+        self
+    }
+
+    /// Sets the key derivation operations to perform on a key.
+    pub const fn set_derived_type(&mut self, derived_type: DerivedKeyTypes) {
+        self.derived_type = derived_type;
+    }
+
+    /// Sets the key derivation operations to perform on a key and returns the `Context`.
+    #[must_use]
+    pub const fn with_derived_type(mut self, derived_type: DerivedKeyTypes) -> Self {
+        self.set_derived_type(derived_type);
+        self
+    }
+
+    /// Sets the EUI64 associated with this key and marks it as valid.
+    pub const fn set_eui64(&mut self, eui64: MacAddr8) {
+        self.eui64 = eui64;
+        self.flags = self.flags.union(Flags::EUI_IS_VALID);
+    }
+
+    /// Sets the EUI64 associated with this key, marks it as valid and returns the `Context`.
+    #[must_use]
+    pub const fn with_eui64(mut self, eui64: MacAddr8) -> Self {
+        self.set_eui64(eui64);
+        self
+    }
+
+    /// Sets the multi-network index.
+    pub const fn set_multi_network_index(&mut self, multi_network_index: u8) {
+        self.multi_network_index = multi_network_index;
+    }
+
+    /// Sets the multi-network index and returns the `Context`.
+    #[must_use]
+    pub const fn with_multi_network_index(mut self, multi_network_index: u8) -> Self {
+        self.set_multi_network_index(multi_network_index);
+        self
+    }
+
+    /// Sets the flag bitmask.
+    ///
+    /// This replaces the flags set by [`set_key_index()`](Self::set_key_index) and
+    /// [`set_eui64()`](Self::set_eui64).
+    pub const fn set_flags(&mut self, flags: Flags) {
+        self.flags = flags;
+    }
+
+    /// Sets the flag bitmask and returns the `Context`.
+    #[must_use]
+    pub const fn with_flags(mut self, flags: Flags) -> Self {
+        self.set_flags(flags);
+        self
+    }
+
+    /// Sets the algorithm to use with this key (for PSA APIs).
+    pub const fn set_psa_key_alg_permission(&mut self, psa_key_alg_permission: u32) {
+        self.psa_key_alg_permission = psa_key_alg_permission;
+    }
+
+    /// Sets the algorithm to use with this key (for PSA APIs) and returns the `Context`.
+    #[must_use]
+    pub const fn with_psa_key_alg_permission(mut self, psa_key_alg_permission: u32) -> Self {
+        self.set_psa_key_alg_permission(psa_key_alg_permission);
+        self
+    }
+}
+
+/// Mirrors `sl_zb_sec_man_init_context`.
+impl Default for Context {
+    fn default() -> Self {
+        ContextBuilder::new().build()
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for Context {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(
+            f,
+            "Context {{ core_key_type: {}, key_index: {=u8}, derived_type: {}, eui64: {=[u8]:02x}, multi_network_index: {=u8}, flags: {}, psa_key_alg_permission: {=u32:#010x} }}",
+            self.core_key_type,
+            self.key_index,
+            self.derived_type,
+            self.eui64.as_bytes(),
+            self.multi_network_index,
+            self.flags,
+            self.psa_key_alg_permission,
+        );
+    }
 }
@@ -0,0 +1,124 @@
+use macaddr::MacAddr8;
+
+use crate::zigbee::security::man::{Context, DerivedKeyTypes, Flags, KeyType};
+
+/// Builder for a [`Context`].
+///
+/// The builder starts out with the values set by `sl_zb_sec_man_init_context` and sets the
+/// [`Flags::KEY_INDEX_IS_VALID`] and [`Flags::EUI_IS_VALID`] flags when the respective field is set.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ContextBuilder {
+    core_key_type: KeyType,
+    key_index: u8,
+    derived_type: DerivedKeyTypes,
+    eui64: MacAddr8,
+    multi_network_index: u8,
+    flags: Flags,
+    psa_key_alg_permission: u32,
+}
+
+impl ContextBuilder {
+    /// Creates a new `ContextBuilder`.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            core_key_type: KeyType::None,
+            key_index: 0,
+            derived_type: DerivedKeyTypes::NONE,
+            eui64: MacAddr8::nil(),
+            multi_network_index: 0,
+            flags: Flags::NONE,
+            psa_key_alg_permission: Context::DEFAULT_PSA_KEY_ALG_PERMISSION,
+        }
+    }
+
+    /// Sets the type of key being referenced.
+    #[must_use]
+    pub const fn core_key_type(mut self, core_key_type: KeyType) -> Self {
+        self.core_key_type = core_key_type;
+        self
+    }
+
+    /// Sets the index of the referenced key and marks it as valid.
+    #[must_use]
+    pub const fn key_index(mut self, key_index: u8) -> Self {
+        self.key_index = key_index;
+        self.flags = self.flags.union(Flags::KEY_INDEX_IS_VALID);
+        self
+    }
+
+    /// Sets the key derivation operations to perform on a key.
+    #[must_use]
+    pub const fn derived_type(mut self, derived_type: DerivedKeyTypes) -> Self {
+        self.derived_type = derived_type;
+        self
+    }
+
+    /// Sets the EUI64 associated with the key and marks it as valid.
+    #[must_use]
+    pub const fn eui64(mut self, eui64: MacAddr8) -> Self {
+        self.eui64 = eui64;
+        self.flags = self.flags.union(Flags::EUI_IS_VALID);
+        self
+    }
+
+    /// Sets the multi-network index.
+    #[must_use]
+    pub const fn multi_network_index(mut self, multi_network_index: u8) -> Self {
+        self.multi_network_index = multi_network_index;
+        self
+    }
+
+    /// Sets additional flags.
+    ///
+    /// Flags set by [`key_index()`](Self::key_index) and [`eui64()`](Self::eui64) are retained.
+    #[must_use]
+    pub const fn flags(mut self, flags: Flags) -> Self {
+        self.flags = self.flags.union(flags);
+        self
+    }
+
+    /// Sets the algorithm to use with the key (for PSA APIs).
+    #[must_use]
+    pub const fn psa_key_alg_permission(mut self, psa_key_alg_permission: u32) -> Self {
+        self.psa_key_alg_permission = psa_key_alg_permission;
+        self
+    }
+
+    /// Builds the `Context`.
+    #[must_use]
+    pub const fn build(self) -> Context {
+        Context::new(
+            self.core_key_type,
+            self.key_index,
+            self.derived_type,
+            self.eui64,
+            self.multi_network_index,
+            self.flags,
+            self.psa_key_alg_permission,
+        )
+    }
+}
+
+impl Default for ContextBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for ContextBuilder {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(
+            f,
+            "ContextBuilder {{ core_key_type: {}, key_index: {=u8}, derived_type: {}, eui64: {=[u8]:02x}, multi_network_index: {=u8}, flags: {}, psa_key_alg_permission: {=u32:#010x} }}",
+            self.core_key_type,
+            self.key_index,
+            self.derived_type,
+            self.eui64.as_bytes(),
+            self.multi_network_index,
+            self.flags,
+            self.psa_key_alg_permission,
+        );
+    }
+}
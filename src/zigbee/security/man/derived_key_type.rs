@@ -1,35 +1,41 @@
-use core::fmt::{self, Display, LowerHex, UpperHex};
-
 use num_derive::FromPrimitive;
 
-/// Derived keys are calculated when performing Zigbee crypto operations.
-///
-/// The stack makes use of these derivations.
-///
-/// Compounding derivations can be specified by using an or-equals on two derived types if
-/// applicable; this is limited to performing the key-transport, key-load, or verify-key hashes on
-/// either the TC Swap Out or TC Hashed Link keys.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Ord, PartialOrd, FromPrimitive)]
-#[repr(u16)]
-pub enum DerivedKeyType {
-    /// Perform no derivation; use the key as is.
-    None = 0x0000,
+use crate::macros::sdk_enum;
+
+sdk_enum! {
+    prefix: "SL_ZB_SEC_MAN_DERIVED_KEY_TYPE_",
+    noun: "derived key type",
+    repr: u16,
 
-    /// Perform the Key-Transport-Key hash.
-    TransportKey = 0x0001,
+    /// Derived keys are calculated when performing Zigbee crypto operations.
+    ///
+    /// The stack makes use of these derivations.
+    ///
+    /// Compounding derivations can be specified by using an or-equals on two derived types if
+    /// applicable; this is limited to performing the key-transport, key-load, or verify-key hashes on
+    /// either the TC Swap Out or TC Hashed Link keys. Such compounds are represented by
+    /// [`DerivedKeyTypes`](crate::zigbee::security::man::DerivedKeyTypes).
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Ord, PartialOrd, FromPrimitive)]
+    pub enum DerivedKeyType {
+        /// Perform no derivation; use the key as is.
+        None = 0x0000,
 
-    /// Perform the Key-Load-Key hash.
-    LoadKey = 0x0002,
+        /// Perform the Key-Transport-Key hash.
+        TransportKey = 0x0001,
 
-    /// Perform the Verify Key hash.
-    VerifyKey = 0x0004,
+        /// Perform the Key-Load-Key hash.
+        LoadKey = 0x0002,
 
-    /// Perform a simple AES hash of the key for TC backup.
-    TcSwapOutKey = 0x0008,
+        /// Perform the Verify Key hash.
+        VerifyKey = 0x0004,
 
-    /// For a TC using hashed link keys, hashed the root key against the supplied EUI in context.
-    TcHashedLinkKey = 0x0010,
+        /// Perform a simple AES hash of the key for TC backup.
+        TcSwapOutKey = 0x0008,
+
+        /// For a TC using hashed link keys, hashed the root key against the supplied EUI in context.
+        TcHashedLinkKey = 0x0010,
+    }
 }
 
 impl DerivedKeyType {
@@ -45,22 +51,19 @@ impl DerivedKeyType {
             Self::TcHashedLinkKey => "SL_ZB_SEC_MAN_DERIVED_KEY_TYPE_TC_HASHED_LINK_KEY",
         }
     }
-}
-
-impl Display for DerivedKeyType {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.as_str())
-    }
-}
-
-impl LowerHex for DerivedKeyType {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:#06x}", *self as u16)
-    }
-}
 
-impl UpperHex for DerivedKeyType {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:#06X}", *self as u16)
+    /// Return a human-readable description of the derived key type.
+    #[must_use]
+    pub const fn description(self) -> &'static str {
+        match self {
+            Self::None => "Perform no derivation; use the key as is.",
+            Self::TransportKey => "Perform the Key-Transport-Key hash.",
+            Self::LoadKey => "Perform the Key-Load-Key hash.",
+            Self::VerifyKey => "Perform the Verify Key hash.",
+            Self::TcSwapOutKey => "Perform a simple AES hash of the key for TC backup.",
+            Self::TcHashedLinkKey => {
+                "For a TC using hashed link keys, hashed the root key against the supplied EUI in context."
+            }
+        }
     }
 }
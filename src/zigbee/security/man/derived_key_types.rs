@@ -0,0 +1,157 @@
+use bitflags::bitflags;
+use num_traits::FromPrimitive;
+
+use crate::zigbee::security::man::DerivedKeyType;
+
+/// Base hashes, of which at most one may be performed.
+const HASHES: DerivedKeyTypes = DerivedKeyTypes::TRANSPORT_KEY
+    .union(DerivedKeyTypes::LOAD_KEY)
+    .union(DerivedKeyTypes::VERIFY_KEY);
+
+/// Trust Center keys, of which at most one may be derived from.
+const TC_KEYS: DerivedKeyTypes =
+    DerivedKeyTypes::TC_SWAP_OUT_KEY.union(DerivedKeyTypes::TC_HASHED_LINK_KEY);
+
+/// A compound of [`DerivedKeyType`]s.
+///
+/// The key-transport, key-load or verify-key hash may be performed on either the TC Swap Out or
+/// TC Hashed Link key. Values combining several hashes or several of those keys are
+/// [invalid](Self::is_valid).
+///
+/// # Documentation
+///
+/// See [docs.silabs.com](https://docs.silabs.com/d/zigbee-stack-api/7.2.2/zigbee-security-manager#sl-zb-sec-man-derived-key-type-t) for further information.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "le-stream",
+    derive(le_stream::FromLeStream, le_stream::ToLeStream)
+)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Ord, PartialOrd)]
+pub struct DerivedKeyTypes(u16);
+
+bitflags! {
+    impl DerivedKeyTypes: u16 {
+        /// Perform no derivation; use the key as is.
+        const NONE = DerivedKeyType::None as u16;
+
+        /// Perform the Key-Transport-Key hash.
+        const TRANSPORT_KEY = DerivedKeyType::TransportKey as u16;
+
+        /// Perform the Key-Load-Key hash.
+        const LOAD_KEY = DerivedKeyType::LoadKey as u16;
+
+        /// Perform the Verify Key hash.
+        const VERIFY_KEY = DerivedKeyType::VerifyKey as u16;
+
+        /// Perform a simple AES hash of the key for TC backup.
+        const TC_SWAP_OUT_KEY = DerivedKeyType::TcSwapOutKey as u16;
+
+        /// For a TC using hashed link keys, hashed the root key against the supplied EUI in context.
+        const TC_HASHED_LINK_KEY = DerivedKeyType::TcHashedLinkKey as u16;
+    }
+}
+
+impl DerivedKeyTypes {
+    /// Determines whether the derivations may be combined.
+    #[must_use]
+    pub const fn is_valid(self) -> bool {
+        Self::all().contains(self)
+            && self.intersection(HASHES).bits().count_ones() <= 1
+            && self.intersection(TC_KEYS).bits().count_ones() <= 1
+    }
+}
+
+impl From<DerivedKeyType> for DerivedKeyTypes {
+    fn from(derived_type: DerivedKeyType) -> Self {
+        Self::from_bits_retain(derived_type as u16)
+    }
+}
+
+impl TryFrom<DerivedKeyTypes> for DerivedKeyType {
+    type Error = DerivedKeyTypes;
+
+    fn try_from(derived_types: DerivedKeyTypes) -> Result<Self, Self::Error> {
+        Self::from_u16(derived_types.bits()).ok_or(derived_types)
+    }
+}
+
+/// Rejects unknown flags and invalid combinations.
+impl TryFrom<u16> for DerivedKeyTypes {
+    type Error = u16;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        Some(Self::from_bits_retain(value))
+            .filter(|derived_types| derived_types.is_valid())
+            .ok_or(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DerivedKeyTypes;
+
+    #[test]
+    fn single_derivations_are_valid() {
+        for derived_types in [
+            DerivedKeyTypes::NONE,
+            DerivedKeyTypes::TRANSPORT_KEY,
+            DerivedKeyTypes::LOAD_KEY,
+            DerivedKeyTypes::VERIFY_KEY,
+            DerivedKeyTypes::TC_SWAP_OUT_KEY,
+            DerivedKeyTypes::TC_HASHED_LINK_KEY,
+        ] {
+            assert!(derived_types.is_valid(), "{derived_types:?}");
+        }
+    }
+
+    #[test]
+    fn hash_of_tc_key_is_valid() {
+        for hash in [
+            DerivedKeyTypes::TRANSPORT_KEY,
+            DerivedKeyTypes::LOAD_KEY,
+            DerivedKeyTypes::VERIFY_KEY,
+        ] {
+            for tc_key in [
+                DerivedKeyTypes::TC_SWAP_OUT_KEY,
+                DerivedKeyTypes::TC_HASHED_LINK_KEY,
+            ] {
+                assert!(hash.union(tc_key).is_valid(), "{hash:?} | {tc_key:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn multiple_hashes_are_invalid() {
+        assert!(
+            !DerivedKeyTypes::TRANSPORT_KEY
+                .union(DerivedKeyTypes::LOAD_KEY)
+                .is_valid()
+        );
+        assert!(
+            !DerivedKeyTypes::LOAD_KEY
+                .union(DerivedKeyTypes::VERIFY_KEY)
+                .union(DerivedKeyTypes::TC_SWAP_OUT_KEY)
+                .is_valid()
+        );
+    }
+
+    #[test]
+    fn multiple_tc_keys_are_invalid() {
+        assert!(
+            !DerivedKeyTypes::TC_SWAP_OUT_KEY
+                .union(DerivedKeyTypes::TC_HASHED_LINK_KEY)
+                .is_valid()
+        );
+    }
+
+    #[test]
+    fn unknown_bits_are_invalid() {
+        assert!(!DerivedKeyTypes::from_bits_retain(0x8000).is_valid());
+        assert_eq!(DerivedKeyTypes::try_from(0x8000), Err(0x8000));
+        assert_eq!(
+            DerivedKeyTypes::try_from(DerivedKeyTypes::LOAD_KEY.bits()),
+            Ok(DerivedKeyTypes::LOAD_KEY)
+        );
+    }
+}
@@ -1,2 +1,48 @@
 /// This data structure contains the key data that is passed into various other functions.
-pub type Key = [u8; 16];
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "le-stream",
+    derive(le_stream::FromLeStream, le_stream::ToLeStream)
+)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Key([u8; 16]);
+
+impl Key {
+    /// Creates a new `Key`.
+    #[must_use]
+    pub const fn new(bytes: [u8; 16]) -> Self {
+        Self(bytes)
+    }
+
+    /// Returns the key data.
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8; 16] {
+        &self.0
+    }
+}
+
+impl AsRef<[u8]> for Key {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<[u8; 16]> for Key {
+    fn from(bytes: [u8; 16]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<Key> for [u8; 16] {
+    fn from(key: Key) -> Self {
+        key.0
+    }
+}
+
+#[cfg(feature = "defmt")]
+/// The key data is redacted.
+impl defmt::Format for Key {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "Key(<redacted>)");
+    }
+}
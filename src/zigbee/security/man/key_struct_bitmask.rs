@@ -0,0 +1,62 @@
+use bitflags::bitflags;
+
+/// Describes the presence of valid data within the fields of a key struct.
+///
+/// # Documentation
+///
+/// See [docs.silabs.com](https://docs.silabs.com/d/zigbee-stack-api/7.2.2/zigbee-stack-types#ember-key-struct-bitmask) for further information.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "le-stream",
+    derive(le_stream::FromLeStream, le_stream::ToLeStream)
+)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Ord, PartialOrd)]
+pub struct KeyStructBitmask(u16);
+
+bitflags! {
+    impl KeyStructBitmask: u16 {
+        /// No flags are set.
+        const NONE = 0x0000;
+
+        /// The key has a sequence number associated with it (i.e., a network key).
+        const HAS_SEQUENCE_NUMBER = 0x0001;
+
+        /// The key has an outgoing frame counter and the corresponding field contains a valid value.
+        const HAS_OUTGOING_FRAME_COUNTER = 0x0002;
+
+        /// The key has an incoming frame counter and the corresponding field contains a valid value.
+        const HAS_INCOMING_FRAME_COUNTER = 0x0004;
+
+        /// The key has a partner IEEE address and the corresponding field contains a valid value.
+        const HAS_PARTNER_EUI64 = 0x0008;
+
+        /// The key is authorized for use in APS data messages.
+        ///
+        /// If the key is not authorized for use in APS data messages, it has not yet gone through
+        /// a key agreement protocol, such as CBKE (i.e., ECC).
+        const IS_AUTHORIZED = 0x0010;
+
+        /// The partner associated with the link is a sleepy end device.
+        ///
+        /// This bit is set automatically if the local device hears a device announce message from
+        /// the partner indicating it is not an 'RX on when idle' device.
+        const PARTNER_IS_SLEEPY = 0x0020;
+
+        /// The transient key which is being added is unconfirmed.
+        ///
+        /// This bit is set when the transient key being added is an unconfirmed, updated key
+        /// which is awaiting a Verify Key Confirm message.
+        const UNCONFIRMED_TRANSIENT_KEY = 0x0040;
+
+        /// A PSA key identifier is stored in the key struct.
+        const HAS_PSA_ID = 0x0080;
+
+        /// Plaintext key data is stored in the key struct.
+        const HAS_KEY_DATA = 0x0100;
+
+        /// The key is an authentication token, i.e. it was not obtained through the
+        /// Trust Center Link Key update procedure.
+        const IS_AUTHENTICATION_TOKEN = 0x0200;
+    }
+}
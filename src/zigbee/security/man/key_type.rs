@@ -1,73 +1,78 @@
-use core::fmt::{self, Display, LowerHex, UpperHex};
-
 use num_derive::FromPrimitive;
 
-/// The list of supported key types used by Zigbee Security Manager.
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Ord, PartialOrd, FromPrimitive)]
-#[repr(u8)]
-pub enum KeyType {
-    /// No key type.
-    None = 0,
+use crate::macros::sdk_enum;
+
+sdk_enum! {
+    prefix: "SL_ZB_SEC_MAN_KEY_TYPE_",
+    noun: "key type",
+    repr: u8,
 
-    /// This is the network key, used for encrypting and decrypting network payloads.
-    ///
-    /// There is only one of these keys in storage.
-    Network = 1,
+    /// The list of supported key types used by Zigbee Security Manager.
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Ord, PartialOrd, FromPrimitive)]
+    pub enum KeyType {
+        /// No key type.
+        None = 0,
 
-    /// This is the Trust Center Link Key.
-    ///
-    /// On the joining device, this is the APS key used to communicate with the trust center.
-    /// On the trust center, this key can be used as a root key for APS encryption and decryption
-    /// when communicating with joining devices (if the security policy has the
-    /// `EMBER_TRUST_CENTER_USES_HASHED_LINK_KEY` bit set).
-    ///
-    /// There is only one of these keys in storage.
-    TcLink = 2,
+        /// This is the network key, used for encrypting and decrypting network payloads.
+        ///
+        /// There is only one of these keys in storage.
+        Network = 1,
 
-    /// This is a Trust Center Link Key, but it times out after either
-    ///
-    ///   - `EMBER_TRANSIENT_KEY_TIMEOUT_S` or
-    ///   - `EMBER_AF_PLUGIN_NETWORK_CREATOR_SECURITY_NETWORK_OPEN_TIME_S` (if defined),
-    ///
-    /// whichever is longer.
-    ///
-    /// This type of key is set on trust centers who wish to open joining with a temporary,
-    /// or transient, APS key for devices to join with.
-    ///
-    /// Joiners who wish to try several keys when joining a network may set several of these types
-    /// of keys before attempting to join.
-    ///
-    /// This is an indexed key, and local storage can fit as many keys as available RAM allows.
-    TcLinkWithTimeout = 3,
+        /// This is the Trust Center Link Key.
+        ///
+        /// On the joining device, this is the APS key used to communicate with the trust center.
+        /// On the trust center, this key can be used as a root key for APS encryption and decryption
+        /// when communicating with joining devices (if the security policy has the
+        /// `EMBER_TRUST_CENTER_USES_HASHED_LINK_KEY` bit set).
+        ///
+        /// There is only one of these keys in storage.
+        TcLink = 2,
 
-    /// This is an Application link key.
-    ///
-    /// On both joining devices and the trust center, this key is used in APS encryption and
-    /// decryption when communicating to a joining device.
-    ///
-    /// This is an indexed key table of size `EMBER_KEY_TABLE_SIZE`, so long as there is sufficient
-    /// nonvolatile memory to store keys.
-    AppLink = 4,
+        /// This is a Trust Center Link Key, but it times out after either
+        ///
+        ///   - `EMBER_TRANSIENT_KEY_TIMEOUT_S` or
+        ///   - `EMBER_AF_PLUGIN_NETWORK_CREATOR_SECURITY_NETWORK_OPEN_TIME_S` (if defined),
+        ///
+        /// whichever is longer.
+        ///
+        /// This type of key is set on trust centers who wish to open joining with a temporary,
+        /// or transient, APS key for devices to join with.
+        ///
+        /// Joiners who wish to try several keys when joining a network may set several of these types
+        /// of keys before attempting to join.
+        ///
+        /// This is an indexed key, and local storage can fit as many keys as available RAM allows.
+        TcLinkWithTimeout = 3,
 
-    /// This is the ZLL encryption key for use by algorithms that require it.
-    ZllEncryptionKey = 6,
+        /// This is an Application link key.
+        ///
+        /// On both joining devices and the trust center, this key is used in APS encryption and
+        /// decryption when communicating to a joining device.
+        ///
+        /// This is an indexed key table of size `EMBER_KEY_TABLE_SIZE`, so long as there is sufficient
+        /// nonvolatile memory to store keys.
+        AppLink = 4,
 
-    /// For ZLL, this is the pre-configured link key used during classical `ZigBee` commissioning.
-    ZllPreconfiguredKey = 7,
+        /// This is the ZLL encryption key for use by algorithms that require it.
+        ZllEncryptionKey = 6,
 
-    /// This is a Green Power Device (GPD) key used on a Proxy device.
-    GreenPowerProxyTableKey = 8,
+        /// For ZLL, this is the pre-configured link key used during classical `ZigBee` commissioning.
+        ZllPreconfiguredKey = 7,
 
-    /// This is a Green Power Device (GPD) key used on a Sink device.
-    GreenPowerSinkTableKey = 9,
+        /// This is a Green Power Device (GPD) key used on a Proxy device.
+        GreenPowerProxyTableKey = 8,
 
-    /// his is a generic key type intended to be loaded for one-time hashing or crypto operations.
-    ///
-    /// This key is not persisted.
-    ///
-    /// Intended for use by the Zigbee stack.
-    Internal = 10,
+        /// This is a Green Power Device (GPD) key used on a Sink device.
+        GreenPowerSinkTableKey = 9,
+
+        /// This is a generic key type intended to be loaded for one-time hashing or crypto operations.
+        ///
+        /// This key is not persisted.
+        ///
+        /// Intended for use by the Zigbee stack.
+        Internal = 10,
+    }
 }
 
 impl KeyType {
@@ -87,22 +92,35 @@ impl KeyType {
             Self::Internal => "SL_ZB_SEC_MAN_KEY_TYPE_INTERNAL",
         }
     }
-}
-
-impl Display for KeyType {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.as_str())
-    }
-}
-
-impl LowerHex for KeyType {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:#04x}", *self as u8)
-    }
-}
 
-impl UpperHex for KeyType {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:#04X}", *self as u8)
+    /// Return a human-readable description of the key type.
+    #[must_use]
+    pub const fn description(self) -> &'static str {
+        match self {
+            Self::None => "No key type.",
+            Self::Network => {
+                "This is the network key, used for encrypting and decrypting network payloads."
+            }
+            Self::TcLink => "This is the Trust Center Link Key.",
+            Self::TcLinkWithTimeout => {
+                "This is a Trust Center Link Key, but it times out after a configured period."
+            }
+            Self::AppLink => "This is an Application link key.",
+            Self::ZllEncryptionKey => {
+                "This is the ZLL encryption key for use by algorithms that require it."
+            }
+            Self::ZllPreconfiguredKey => {
+                "For ZLL, this is the pre-configured link key used during classical ZigBee commissioning."
+            }
+            Self::GreenPowerProxyTableKey => {
+                "This is a Green Power Device (GPD) key used on a Proxy device."
+            }
+            Self::GreenPowerSinkTableKey => {
+                "This is a Green Power Device (GPD) key used on a Sink device."
+            }
+            Self::Internal => {
+                "This is a generic key type intended to be loaded for one-time hashing or crypto operations."
+            }
+        }
     }
 }
@@ -0,0 +1,170 @@
+use macaddr::MacAddr8;
+
+pub use self::invalid_context::InvalidContext;
+use crate::zigbee::security::man::{Context, DerivedKeyTypes, Flags, KeyType};
+
+mod invalid_context;
+
+/// Flags which are implied by the optional fields of a [`ValidatedContext`].
+const VALIDITY_FLAGS: Flags = Flags::KEY_INDEX_IS_VALID.union(Flags::EUI_IS_VALID);
+
+/// Context for Zigbee Security Manager operations with validated fields.
+///
+/// Unlike [`Context`], which mirrors the wire format, this holds the key types as enums and
+/// only exposes the key index and EUI64 if the respective [`Flags`] mark them as valid.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "Context", into = "Context"))]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ValidatedContext {
+    core_key_type: KeyType,
+    key_index: Option<u8>,
+    derived_type: DerivedKeyTypes,
+    eui64: Option<MacAddr8>,
+    multi_network_index: u8,
+    flags: Flags,
+    psa_key_alg_permission: u32,
+}
+
+impl ValidatedContext {
+    /// Creates a new `ValidatedContext`.
+    ///
+    /// The [`Flags::KEY_INDEX_IS_VALID`] and [`Flags::EUI_IS_VALID`] flags are derived from
+    /// whether `key_index` and `eui64` are present, regardless of their state in `flags`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InvalidContext`] if the derived key types are not
+    /// [valid](DerivedKeyTypes::is_valid).
+    pub const fn new(
+        core_key_type: KeyType,
+        key_index: Option<u8>,
+        derived_type: DerivedKeyTypes,
+        eui64: Option<MacAddr8>,
+        multi_network_index: u8,
+        flags: Flags,
+        psa_key_alg_permission: u32,
+    ) -> Result<Self, InvalidContext> {
+        if !derived_type.is_valid() {
+            return Err(InvalidContext::DerivedType(derived_type.bits()));
+        }
+
+        let context = Self {
+            core_key_type,
+            key_index,
+            derived_type,
+            eui64,
+            multi_network_index,
+            flags,
+            psa_key_alg_permission,
+        };
+
+        Ok(Self {
+            flags: context.flags(),
+            ..context
+        })
+    }
+
+    /// Returns the type of key being referenced.
+    #[must_use]
+    pub const fn core_key_type(&self) -> KeyType {
+        self.core_key_type
+    }
+
+    /// Returns the index of the referenced key, if it is valid.
+    #[must_use]
+    pub const fn key_index(&self) -> Option<u8> {
+        self.key_index
+    }
+
+    /// Returns the key derivation operations to perform on a key.
+    #[must_use]
+    pub const fn derived_type(&self) -> DerivedKeyTypes {
+        self.derived_type
+    }
+
+    /// Return the EUI64 associated with this key, if it is valid.
+    #[must_use]
+    pub const fn eui64(&self) -> Option<MacAddr8> {
+        self.eui64
+    }
+
+    /// Returns the multi-network index.
+    #[must_use]
+    pub const fn multi_network_index(&self) -> u8 {
+        self.multi_network_index
+    }
+
+    /// Returns the flag bitmask.
+    #[must_use]
+    pub const fn flags(&self) -> Flags {
+        let mut flags = self.flags.difference(VALIDITY_FLAGS);
+
+        if self.key_index.is_some() {
+            flags = flags.union(Flags::KEY_INDEX_IS_VALID);
+        }
+
+        if self.eui64.is_some() {
+            flags = flags.union(Flags::EUI_IS_VALID);
+        }
+
+        flags
+    }
+
+    /// Returns the algorithm to use with this key (for PSA APIs).
+    #[must_use]
+    pub const fn psa_key_alg_permission(&self) -> u32 {
+        self.psa_key_alg_permission
+    }
+}
+
+impl From<ValidatedContext> for Context {
+    fn from(context: ValidatedContext) -> Self {
+        Self::new(
+            context.core_key_type,
+            context.key_index.unwrap_or_default(),
+            context.derived_type,
+            context.eui64.unwrap_or_default(),
+            context.multi_network_index,
+            context.flags(),
+            context.psa_key_alg_permission,
+        )
+    }
+}
+
+/// A key index or EUI64 which is not marked as valid by the flags is ignored.
+impl TryFrom<Context> for ValidatedContext {
+    type Error = InvalidContext;
+
+    fn try_from(context: Context) -> Result<Self, Self::Error> {
+        let flags = context.flags();
+
+        Self::new(
+            context.core_key_type(),
+            flags
+                .contains(Flags::KEY_INDEX_IS_VALID)
+                .then(|| context.key_index()),
+            context.derived_type(),
+            flags.contains(Flags::EUI_IS_VALID).then(|| context.eui64()),
+            context.multi_network_index(),
+            flags,
+            context.psa_key_alg_permission(),
+        )
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for ValidatedContext {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(
+            f,
+            "ValidatedContext {{ core_key_type: {}, key_index: {}, derived_type: {}, eui64: {}, multi_network_index: {=u8}, flags: {}, psa_key_alg_permission: {=u32:#010x} }}",
+            self.core_key_type,
+            self.key_index,
+            self.derived_type,
+            self.eui64.as_ref().map(MacAddr8::as_bytes),
+            self.multi_network_index,
+            self.flags(),
+            self.psa_key_alg_permission,
+        );
+    }
+}
@@ -0,0 +1,25 @@
+use core::error::Error;
+use core::fmt::{self, Display};
+
+/// Error returned when a [`Context`](crate::zigbee::security::man::Context) cannot be validated.
+///
+/// Each variant names the invalid field.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum InvalidContext {
+    /// The derived type is not a valid
+    /// [`DerivedKeyTypes`](crate::zigbee::security::man::DerivedKeyTypes) compound.
+    DerivedType(u16),
+}
+
+impl Display for InvalidContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DerivedType(derived_type) => {
+                write!(f, "invalid derived key type: {derived_type:#06x}")
+            }
+        }
+    }
+}
+
+impl Error for InvalidContext {}